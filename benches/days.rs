@@ -0,0 +1,53 @@
+// times each day's generator and both parts separately against the synthetic
+// inputs in benches/inputs. to compare a change against a baseline:
+//
+//     cargo bench -- --save-baseline before
+//     (make the change)
+//     cargo bench -- --baseline before
+//
+// criterion reports the change for every benchmark and writes an html report
+// under target/criterion. the inputs come from the synthetic generators, days
+// without one are skipped. to rebuild them:
+//
+//     for day in $(seq 1 13); do
+//         cargo run --release -- generate $day --seed $day > benches/inputs/day$day.txt
+//     done
+
+use aoc::y2020::SOLVERS;
+use criterion::{criterion_group, criterion_main, Criterion};
+use std::fs;
+use std::hint::black_box;
+
+fn input(day: u32) -> Option<String> {
+    let path = format!(
+        "{}/benches/inputs/day{}.txt",
+        env!("CARGO_MANIFEST_DIR"),
+        day
+    );
+    fs::read_to_string(&path).ok()
+}
+
+fn days(c: &mut Criterion) {
+    for (day, solver) in SOLVERS {
+        let input = match input(*day) {
+            Some(input) => input,
+            None => continue,
+        };
+        let mut group = c.benchmark_group(format!("day{}", day));
+
+        group.bench_function("generator", |b| {
+            b.iter(|| solver.parse_any(black_box(&input)).unwrap())
+        });
+        let parsed = solver.parse_any(&input).unwrap();
+        for part in 1..=2 {
+            group.bench_function(format!("part{}", part), |b| {
+                b.iter(|| parsed.solve(part).unwrap())
+            });
+        }
+
+        group.finish();
+    }
+}
+
+criterion_group!(benches, days);
+criterion_main!(benches);
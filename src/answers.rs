@@ -0,0 +1,258 @@
+use serde_json::{json, Map, Value};
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+#[derive(Debug)]
+pub enum AnswersError {
+    Io(io::Error),
+    Json(serde_json::Error),
+    Shape(String),
+}
+
+impl fmt::Display for AnswersError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AnswersError::Io(e) => write!(f, "{}", e),
+            AnswersError::Json(e) => write!(f, "{}", e),
+            AnswersError::Shape(at) => write!(f, "expected {}", at),
+        }
+    }
+}
+
+impl Error for AnswersError {}
+
+impl From<io::Error> for AnswersError {
+    fn from(e: io::Error) -> Self {
+        AnswersError::Io(e)
+    }
+}
+
+impl From<serde_json::Error> for AnswersError {
+    fn from(e: serde_json::Error) -> Self {
+        AnswersError::Json(e)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Verdict {
+    Pass,
+    Regression { expected: String, actual: String },
+    Unrecorded(String),
+    Failed(String),
+}
+
+impl Verdict {
+    pub fn is_ok(&self) -> bool {
+        matches!(self, Verdict::Pass | Verdict::Unrecorded(_))
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "ok"),
+            Verdict::Regression { expected, actual } => {
+                write!(f, "REGRESSION, expected {} but got {}", expected, actual)
+            }
+            Verdict::Unrecorded(actual) => write!(f, "{} (no recorded answer)", actual),
+            Verdict::Failed(e) => write!(f, "FAILED while {}", e),
+        }
+    }
+}
+
+// known-correct answers keyed by day, part and input name, None being the
+// day's own input. stored as
+// {"day9": {"part1": {"default": "...", "named": {"alice": "..."}}}}
+// so that an input that happens to be called "default" can't clash with it
+#[derive(Debug, Default, PartialEq)]
+pub struct Answers(BTreeMap<(u32, u32, Option<String>), String>);
+
+fn numbered(key: &str, prefix: &str) -> Result<u32, AnswersError> {
+    key.strip_prefix(prefix)
+        .and_then(|x| x.parse().ok())
+        .ok_or_else(|| AnswersError::Shape(format!("{}<n> but found {:?}", prefix, key)))
+}
+
+fn object<'a>(value: &'a Value, at: &str) -> Result<&'a Map<String, Value>, AnswersError> {
+    value
+        .as_object()
+        .ok_or_else(|| AnswersError::Shape(format!("an object at {}", at)))
+}
+
+// numbers are accepted too, they're compared as text
+fn answer(value: &Value, at: &str) -> Result<String, AnswersError> {
+    match value {
+        Value::String(x) => Ok(x.clone()),
+        Value::Number(x) => Ok(x.to_string()),
+        _ => Err(AnswersError::Shape(format!("a string or number at {}", at))),
+    }
+}
+
+impl Answers {
+    pub fn parse(input: &str) -> Result<Answers, AnswersError> {
+        let mut answers = Answers::default();
+        let value: Value = serde_json::from_str(input)?;
+
+        for (day_key, parts) in object(&value, "the top level")? {
+            let day = numbered(day_key, "day")?;
+            for (part_key, inputs) in object(parts, day_key)? {
+                let part = numbered(part_key, "part")?;
+                let at = format!("{}.{}", day_key, part_key);
+                for (key, value) in object(inputs, &at)? {
+                    match key.as_str() {
+                        "default" => {
+                            let default = answer(value, &format!("{}.default", at))?;
+                            answers.insert(day, part, None, default);
+                        }
+                        "named" => {
+                            let at = format!("{}.named", at);
+                            for (name, value) in object(value, &at)? {
+                                let named = answer(value, &format!("{}.{}", at, name))?;
+                                answers.insert(day, part, Some(name), named);
+                            }
+                        }
+                        _ => {
+                            return Err(AnswersError::Shape(format!(
+                                "\"default\" or \"named\" but found {:?} at {}",
+                                key, at
+                            )))
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(answers)
+    }
+
+    // a missing file is just an empty registry
+    pub fn load(path: &Path) -> Result<Answers, AnswersError> {
+        match fs::read_to_string(path) {
+            Ok(contents) => Answers::parse(&contents),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), AnswersError> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, self.to_json())?;
+        Ok(())
+    }
+
+    pub fn to_json(&self) -> String {
+        let mut days = Map::new();
+        for ((day, part, name), answer) in &self.0 {
+            let parts = days
+                .entry(format!("day{}", day))
+                .or_insert_with(|| json!({}));
+            let inputs = parts
+                .as_object_mut()
+                .unwrap()
+                .entry(format!("part{}", part))
+                .or_insert_with(|| json!({}))
+                .as_object_mut()
+                .unwrap();
+            match name {
+                None => {
+                    inputs.insert("default".to_owned(), json!(answer));
+                }
+                Some(name) => {
+                    inputs
+                        .entry("named")
+                        .or_insert_with(|| json!({}))
+                        .as_object_mut()
+                        .unwrap()
+                        .insert(name.clone(), json!(answer));
+                }
+            }
+        }
+        serde_json::to_string_pretty(&Value::Object(days)).unwrap() + "\n"
+    }
+
+    pub fn get(&self, day: u32, part: u32, name: Option<&str>) -> Option<&str> {
+        self.0
+            .get(&(day, part, name.map(|x| x.to_owned())))
+            .map(|x| x.as_str())
+    }
+
+    pub fn insert(&mut self, day: u32, part: u32, name: Option<&str>, answer: String) {
+        self.0
+            .insert((day, part, name.map(|x| x.to_owned())), answer);
+    }
+
+    pub fn check(&self, day: u32, part: u32, name: Option<&str>, actual: &str) -> Verdict {
+        match self.get(day, part, name) {
+            Some(expected) if expected == actual => Verdict::Pass,
+            Some(expected) => Verdict::Regression {
+                expected: expected.to_owned(),
+                actual: actual.to_owned(),
+            },
+            None => Verdict::Unrecorded(actual.to_owned()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let answers = Answers::parse(
+            r#"{"day9": {"part1": {"default": "127", "named": {"alice": 62, "default": "5"}}}, "day13": {"part2": {"default": "3417"}}}"#,
+        )
+        .unwrap();
+
+        assert_eq!(answers.get(9, 1, None), Some("127"));
+        assert_eq!(answers.get(9, 1, Some("alice")), Some("62"));
+        assert_eq!(answers.get(9, 1, Some("default")), Some("5"));
+        assert_eq!(answers.get(9, 2, None), None);
+        assert_eq!(Answers::parse(&answers.to_json()).unwrap(), answers);
+    }
+
+    #[test]
+    fn bad_shape() {
+        assert!(matches!(
+            Answers::parse(r#"{"nine": {}}"#),
+            Err(AnswersError::Shape(_))
+        ));
+        assert!(matches!(
+            Answers::parse(r#"{"day9": {"part1": {"default": null}}}"#),
+            Err(AnswersError::Shape(_))
+        ));
+        assert!(matches!(
+            Answers::parse(r#"{"day9": {"part1": {"alice": "62"}}}"#),
+            Err(AnswersError::Shape(_))
+        ));
+        assert!(matches!(Answers::parse("{"), Err(AnswersError::Json(_))));
+    }
+
+    #[test]
+    fn check() {
+        let mut answers = Answers::default();
+        answers.insert(1, 1, None, "514579".to_owned());
+        answers.insert(1, 1, Some("default"), "1".to_owned());
+
+        assert_eq!(answers.check(1, 1, None, "514579"), Verdict::Pass);
+        assert_eq!(answers.check(1, 1, Some("default"), "1"), Verdict::Pass);
+        assert_eq!(
+            answers.check(1, 1, None, "1"),
+            Verdict::Regression {
+                expected: "514579".to_owned(),
+                actual: "1".to_owned()
+            }
+        );
+        assert_eq!(
+            answers.check(1, 1, Some("bob"), "1"),
+            Verdict::Unrecorded("1".to_owned())
+        );
+        assert!(!Verdict::Failed("running: panicked".to_owned()).is_ok());
+    }
+}
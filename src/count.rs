@@ -0,0 +1,103 @@
+use std::error::Error;
+use std::fmt::{self, Debug, Display};
+
+#[cfg(feature = "bigint")]
+use num_bigint::BigUint;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Overflow;
+
+impl Display for Overflow {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "count overflowed")
+    }
+}
+
+impl Error for Overflow {}
+
+pub trait Count: Sized + Clone + Debug + Display + PartialEq {
+    fn from_usize(n: usize) -> Self;
+    fn add(&self, other: &Self) -> Result<Self, Overflow>;
+    fn mul(&self, other: &Self) -> Result<Self, Overflow>;
+
+    fn zero() -> Self {
+        Self::from_usize(0)
+    }
+
+    fn one() -> Self {
+        Self::from_usize(1)
+    }
+
+    fn sum<'a, I>(iter: I) -> Result<Self, Overflow>
+    where
+        I: IntoIterator<Item = &'a Self>,
+        Self: 'a,
+    {
+        iter.into_iter().try_fold(Self::zero(), |acc, x| acc.add(x))
+    }
+}
+
+macro_rules! checked_count {
+    ($($t:ty),*) => {$(
+        impl Count for $t {
+            fn from_usize(n: usize) -> Self {
+                n as $t
+            }
+
+            fn add(&self, other: &Self) -> Result<Self, Overflow> {
+                self.checked_add(*other).ok_or(Overflow)
+            }
+
+            fn mul(&self, other: &Self) -> Result<Self, Overflow> {
+                self.checked_mul(*other).ok_or(Overflow)
+            }
+        }
+    )*};
+}
+
+checked_count!(usize, u64, u128);
+
+#[cfg(feature = "bigint")]
+impl Count for BigUint {
+    fn from_usize(n: usize) -> Self {
+        BigUint::from(n)
+    }
+
+    fn add(&self, other: &Self) -> Result<Self, Overflow> {
+        Ok(self + other)
+    }
+
+    fn mul(&self, other: &Self) -> Result<Self, Overflow> {
+        Ok(self * other)
+    }
+}
+
+// the type the puzzle solvers report their counts in
+#[cfg(not(feature = "bigint"))]
+pub type Counter = u128;
+#[cfg(feature = "bigint")]
+pub type Counter = BigUint;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checked() {
+        assert_eq!(u64::MAX.add(&1), Err(Overflow));
+        assert_eq!(u64::MAX.mul(&2), Err(Overflow));
+        assert_eq!(u128::from_usize(3).mul(&4), Ok(12));
+        assert_eq!(u64::sum(&[1, 2, 3]), Ok(6));
+        assert_eq!(u64::sum(&[u64::MAX, 1]), Err(Overflow));
+    }
+
+    #[cfg(feature = "bigint")]
+    #[test]
+    fn bigint() {
+        let big = BigUint::from(u128::MAX);
+        assert_eq!(
+            big.mul(&big).unwrap().to_string(),
+            "115792089237316195423570985008687907852589419931798687112530834793049593217025"
+        );
+    }
+}
@@ -0,0 +1,177 @@
+use std::collections::HashMap;
+use std::env;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Input {
+    // None for the day's own dayN.txt, Some("alice") for dayN.alice.txt
+    pub name: Option<String>,
+    pub path: PathBuf,
+}
+
+// inputs live at <dir>/<year>/dayN.txt, with teammates' inputs next to them
+// as dayN.<name>.txt
+pub struct Inputs {
+    dir: PathBuf,
+    year: u32,
+    overrides: HashMap<u32, PathBuf>,
+}
+
+impl Inputs {
+    pub fn new(dir: impl Into<PathBuf>, year: u32) -> Inputs {
+        Inputs {
+            dir: dir.into(),
+            year,
+            overrides: HashMap::new(),
+        }
+    }
+
+    pub fn from_env(year: u32) -> Inputs {
+        Inputs::from_vars(year, env::vars())
+    }
+
+    // AOC_INPUT_DIR replaces the default "input" directory and
+    // AOC_<year>_DAY<n>_INPUT points a single day's default input at any file
+    pub fn from_vars(year: u32, vars: impl IntoIterator<Item = (String, String)>) -> Inputs {
+        let mut inputs = Inputs::new("input", year);
+        let prefix = format!("AOC_{}_DAY", year);
+        for (key, value) in vars {
+            if key == "AOC_INPUT_DIR" {
+                inputs.dir = value.into();
+            } else if let Some(day) = key
+                .strip_prefix(&prefix)
+                .and_then(|x| x.strip_suffix("_INPUT"))
+                .and_then(|x| x.parse().ok())
+            {
+                inputs.overrides.insert(day, value.into());
+            }
+        }
+        inputs
+    }
+
+    pub fn dir(&self) -> PathBuf {
+        self.dir.join(self.year.to_string())
+    }
+
+    pub fn default(&self, day: u32) -> Input {
+        Input {
+            name: None,
+            path: self
+                .overrides
+                .get(&day)
+                .cloned()
+                .unwrap_or_else(|| self.dir().join(format!("day{}.txt", day))),
+        }
+    }
+
+    pub fn named(&self, day: u32, name: &str) -> Input {
+        Input {
+            name: Some(name.to_owned()),
+            path: self.dir().join(format!("day{}.{}.txt", day, name)),
+        }
+    }
+
+    // every input there is for a day, the default first and then by name
+    pub fn all(&self, day: u32) -> io::Result<Vec<Input>> {
+        let mut names = vec![];
+        let entries = match fs::read_dir(self.dir()) {
+            Ok(entries) => entries.collect::<io::Result<Vec<_>>>()?,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => vec![],
+            Err(e) => return Err(e),
+        };
+        for entry in entries {
+            let file = entry.file_name();
+            let name = file
+                .to_str()
+                .and_then(|x| x.strip_prefix(&format!("day{}.", day)))
+                .and_then(|x| x.strip_suffix(".txt"));
+            match name {
+                Some(name) if !name.is_empty() && !name.contains('.') => {
+                    names.push(name.to_owned())
+                }
+                _ => {}
+            }
+        }
+        names.sort();
+
+        let default = self.default(day);
+        let mut inputs = vec![];
+        if default.path.is_file() {
+            inputs.push(default);
+        }
+        inputs.extend(names.iter().map(|x| self.named(day, x)));
+        Ok(inputs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::process;
+
+    fn vars(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn paths() {
+        let inputs = Inputs::from_vars(2020, vars(&[("HOME", "/root")]));
+        assert_eq!(inputs.default(9).path, PathBuf::from("input/2020/day9.txt"));
+        assert_eq!(
+            inputs.named(9, "alice").path,
+            PathBuf::from("input/2020/day9.alice.txt")
+        );
+
+        let inputs = Inputs::from_vars(
+            2020,
+            vars(&[
+                ("AOC_INPUT_DIR", "/puzzles"),
+                ("AOC_2020_DAY9_INPUT", "mine.txt"),
+                ("AOC_2021_DAY10_INPUT", "theirs.txt"),
+                ("AOC_DAY10_INPUT", "unscoped.txt"),
+            ]),
+        );
+        assert_eq!(inputs.default(9).path, PathBuf::from("mine.txt"));
+        assert_eq!(
+            inputs.default(10).path,
+            PathBuf::from("/puzzles/2020/day10.txt")
+        );
+        assert_eq!(
+            inputs.named(9, "bob").path,
+            PathBuf::from("/puzzles/2020/day9.bob.txt")
+        );
+    }
+
+    #[test]
+    fn all() {
+        let dir = env::temp_dir().join(format!("aoc-inputs-{}", process::id()));
+        let inputs = Inputs::new(&dir, 2020);
+        assert_eq!(inputs.all(9).unwrap(), vec![]);
+
+        fs::create_dir_all(inputs.dir()).unwrap();
+        for file in &[
+            "day9.txt",
+            "day9.bob.txt",
+            "day9.alice.txt",
+            "day99.txt",
+            "day9..txt",
+            "day1.alice.txt",
+        ] {
+            fs::write(inputs.dir().join(file), "").unwrap();
+        }
+
+        let names: Vec<_> = inputs.all(9).unwrap().into_iter().map(|x| x.name).collect();
+        assert_eq!(
+            names,
+            vec![None, Some("alice".to_owned()), Some("bob".to_owned())]
+        );
+        assert_eq!(inputs.all(1).unwrap(), vec![inputs.named(1, "alice")]);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
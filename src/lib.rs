@@ -1,17 +1,16 @@
 use aoc_runner_derive::aoc_lib;
 
-pub mod day1;
-pub mod day10;
-pub mod day11;
-pub mod day12;
-pub mod day13;
-pub mod day2;
-pub mod day3;
-pub mod day4;
-pub mod day5;
-pub mod day6;
-pub mod day7;
-pub mod day8;
-pub mod day9;
+pub mod answers;
+pub mod count;
+pub mod input;
+pub mod profile;
+pub mod scaffold;
+pub mod solver;
+pub mod util;
+#[cfg(target_arch = "wasm32")]
+pub mod wasm;
+pub mod y2020;
 
+// cargo-aoc only knows about one year per crate, every year is reachable
+// through solver::find
 aoc_lib! { year = 2020 }
@@ -0,0 +1,469 @@
+use aoc::answers::{Answers, Verdict};
+use aoc::input::{Input, Inputs};
+use aoc::profile::{self, Counting, Profile};
+use aoc::scaffold;
+use aoc::solver::{self, AnySolver};
+use aoc::y2020::synth;
+use std::error::Error;
+use std::io::Read;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::process;
+use std::time::{Duration, Instant};
+
+#[global_allocator]
+static ALLOCATOR: Counting = Counting::new();
+
+const DEFAULT_YEAR: u32 = 2020;
+
+// every (day, part) the year has a solver for
+fn parts(year: u32) -> impl Iterator<Item = (u32, u32, &'static dyn AnySolver)> {
+    solver::year(year)
+        .unwrap_or(&[])
+        .iter()
+        .flat_map(|(day, solver)| (1..=2).map(move |part| (*day, part, *solver)))
+}
+
+const USAGE: &str = "usage: aoc <day> <part> [--input <path>|- | --name <name> | --every-input]
+       aoc all [--name <name> | --every-input]
+       aoc verify [--record] [--name <name> | --every-input]
+       aoc generate <day> [--seed <n>] [--size <n>]
+       aoc profile [<day>] [--json] [--input <path>|- | --name <name> | --every-input]
+       aoc new-day <day>
+every command takes --year <n>, which is 2020 unless given";
+
+// which of a day's inputs to run against
+enum Source {
+    Default,
+    Path(String),
+    Named(String),
+    Every,
+}
+
+enum Command {
+    Run {
+        day: u32,
+        part: u32,
+        source: Source,
+    },
+    All {
+        source: Source,
+    },
+    Verify {
+        source: Source,
+        record: bool,
+    },
+    Generate {
+        day: u32,
+        seed: u64,
+        size: usize,
+    },
+    Profile {
+        day: Option<u32>,
+        source: Source,
+        json: bool,
+    },
+    NewDay {
+        day: u32,
+    },
+}
+
+fn parse_source(args: &[String]) -> Result<Source, String> {
+    match args {
+        [] => Ok(Source::Default),
+        [flag, path] if flag == "--input" => Ok(Source::Path(path.clone())),
+        [flag, name] if flag == "--name" => Ok(Source::Named(name.clone())),
+        [flag] if flag == "--every-input" => Ok(Source::Every),
+        _ => Err(format!("unexpected arguments {:?}", args)),
+    }
+}
+
+// --year can go anywhere, the rest of the arguments are positional
+fn parse_args(args: &[String]) -> Result<(u32, Command), String> {
+    let mut year = DEFAULT_YEAR;
+    let mut rest = vec![];
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        if arg == "--year" {
+            let n = args.next().ok_or("--year needs a value")?;
+            year = n.parse().map_err(|_| format!("bad year {:?}", n))?;
+        } else {
+            rest.push(arg.clone());
+        }
+    }
+    let command = parse_command(year, &rest)?;
+    // a new day is the one thing that can start a year
+    if solver::year(year).is_none() && !matches!(command, Command::NewDay { .. }) {
+        return Err(format!("no solutions for {}", year));
+    }
+    Ok((year, command))
+}
+
+fn parse_command(year: u32, args: &[String]) -> Result<Command, String> {
+    match args {
+        [all, rest @ ..] if all == "all" => match parse_source(rest)? {
+            Source::Path(_) => Err("--input needs a single day".to_owned()),
+            source => Ok(Command::All { source }),
+        },
+        [verify, rest @ ..] if verify == "verify" => {
+            let record = rest.iter().any(|x| x == "--record");
+            let rest: Vec<_> = rest.iter().filter(|x| *x != "--record").cloned().collect();
+            match parse_source(&rest)? {
+                Source::Path(_) => Err("--input needs a single day".to_owned()),
+                source => Ok(Command::Verify { source, record }),
+            }
+        }
+        [profile, rest @ ..] if profile == "profile" => {
+            let json = rest.iter().any(|x| x == "--json");
+            let mut rest: Vec<_> = rest.iter().filter(|x| *x != "--json").cloned().collect();
+            let day = match rest.first().map(|x| x.parse()) {
+                Some(Ok(day)) => {
+                    rest.remove(0);
+                    if !parts(year).any(|(d, _, _)| d == day) {
+                        return Err(format!("no solution for day {}", day));
+                    }
+                    Some(day)
+                }
+                _ => None,
+            };
+            match parse_source(&rest)? {
+                Source::Path(_) if day.is_none() => Err("--input needs a single day".to_owned()),
+                source => Ok(Command::Profile { day, source, json }),
+            }
+        }
+        [generate, day, rest @ ..] if generate == "generate" => {
+            let day = day.parse().map_err(|_| format!("bad day {:?}", day))?;
+            let mut size = Some(day)
+                .filter(|_| year == 2020)
+                .and_then(synth::typical_size)
+                .ok_or(format!("no generator for day {} of {}", day, year))?;
+            let mut seed = 0;
+            for option in rest.chunks(2) {
+                match option {
+                    [flag, n] if flag == "--seed" => {
+                        seed = n.parse().map_err(|_| format!("bad seed {:?}", n))?
+                    }
+                    [flag, n] if flag == "--size" => {
+                        size = n.parse().map_err(|_| format!("bad size {:?}", n))?
+                    }
+                    _ => return Err(format!("unexpected arguments {:?}", option)),
+                }
+            }
+            Ok(Command::Generate { day, seed, size })
+        }
+        [new_day, day] if new_day == "new-day" => match day.parse() {
+            Ok(day) if (1..=25).contains(&day) => Ok(Command::NewDay { day }),
+            _ => Err(format!("bad day {:?}", day)),
+        },
+        [day, part, rest @ ..] => {
+            let day = day.parse().map_err(|_| format!("bad day {:?}", day))?;
+            let part = part.parse().map_err(|_| format!("bad part {:?}", part))?;
+            if !parts(year).any(|(d, p, _)| (d, p) == (day, part)) {
+                return Err(format!("no solution for day {} part {}", day, part));
+            }
+            let source = parse_source(rest)?;
+            Ok(Command::Run { day, part, source })
+        }
+        _ => Err("missing arguments".to_owned()),
+    }
+}
+
+fn resolve(inputs: &Inputs, day: u32, source: &Source) -> Result<Vec<Input>, String> {
+    Ok(match source {
+        Source::Default => vec![inputs.default(day)],
+        Source::Path(path) => vec![Input {
+            name: None,
+            path: path.into(),
+        }],
+        Source::Named(name) => vec![inputs.named(day, name)],
+        Source::Every => inputs
+            .all(day)
+            .map_err(|e| format!("couldn't list {}: {}", inputs.dir().display(), e))?,
+    })
+}
+
+// "-" reads the whole of stdin
+fn read_input(path: &Path) -> Result<String, String> {
+    let mut contents = String::new();
+    if path == Path::new("-") {
+        std::io::stdin()
+            .read_to_string(&mut contents)
+            .map_err(|e| format!("couldn't read stdin: {}", e))?;
+    } else {
+        contents = std::fs::read_to_string(path)
+            .map_err(|e| format!("couldn't read {}: {}", path.display(), e))?;
+    }
+    Ok(contents)
+}
+
+struct Outcome {
+    answer: String,
+    generator: Duration,
+    runner: Duration,
+}
+
+// a panic is reported as a failure like any other error
+fn guarded<T>(step: &str, f: impl FnOnce() -> Result<T, Box<dyn Error>>) -> Result<T, String> {
+    match panic::catch_unwind(AssertUnwindSafe(f)) {
+        Ok(Ok(x)) => Ok(x),
+        Ok(Err(e)) => Err(format!("{}: {}", step, e)),
+        Err(_) => Err(format!("{}: panicked", step)),
+    }
+}
+
+fn run(solver: &dyn AnySolver, part: u32, input: &str) -> Result<Outcome, String> {
+    let start = Instant::now();
+    let parsed = guarded("generating", || solver.parse_any(input))?;
+    let generated = Instant::now();
+    let answer = guarded("running", || parsed.solve(part))?;
+    let finished = Instant::now();
+
+    Ok(Outcome {
+        answer: answer.to_string(),
+        generator: generated - start,
+        runner: finished - generated,
+    })
+}
+
+// the generator and then each part, measured separately
+fn profile(
+    year: u32,
+    day: u32,
+    solver: &dyn AnySolver,
+    input: &Input,
+    contents: &str,
+) -> (Vec<Profile>, Vec<String>) {
+    let step = |step: &str, usage| Profile {
+        year,
+        day,
+        input: input.name.clone(),
+        step: step.to_owned(),
+        usage,
+    };
+    let (mut profiles, mut failures) = (vec![], vec![]);
+
+    let (parsed, usage) =
+        ALLOCATOR.measure(|| guarded("generating", || solver.parse_any(contents)));
+    let parsed = match parsed {
+        Ok(parsed) => parsed,
+        Err(e) => return (profiles, vec![e]),
+    };
+    profiles.push(step("generator", usage));
+
+    for part in 1..=2 {
+        let (answer, usage) = ALLOCATOR.measure(|| guarded("running", || parsed.solve(part)));
+        match answer {
+            Ok(_) => profiles.push(step(&format!("part{}", part), usage)),
+            Err(e) => failures.push(format!("part {}: {}", part, e)),
+        }
+    }
+    (profiles, failures)
+}
+
+// AOC_ANSWERS overrides where the known answers are kept
+fn answers_path(inputs: &Inputs) -> PathBuf {
+    std::env::var_os("AOC_ANSWERS")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| inputs.dir().join("answers.json"))
+}
+
+fn label(day: u32, part: u32, input: &Input) -> String {
+    match &input.name {
+        Some(name) => format!("Day {} - Part {} - {}", day, part, name),
+        None => format!("Day {} - Part {}", day, part),
+    }
+}
+
+fn report(label: &str, outcome: Result<Outcome, String>) -> bool {
+    match outcome {
+        Ok(outcome) => {
+            println!(
+                "{}: {}\n\tgenerator: {:?},\n\trunner: {:?}",
+                label, outcome.answer, outcome.generator, outcome.runner
+            );
+            true
+        }
+        Err(e) => {
+            eprintln!("{}: FAILED while {}", label, e);
+            false
+        }
+    }
+}
+
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let (year, command) = match parse_args(&args) {
+        Ok(parsed) => parsed,
+        Err(e) => {
+            eprintln!("{}\n{}", e, USAGE);
+            process::exit(2);
+        }
+    };
+    let inputs = Inputs::from_env(year);
+
+    let ok = match command {
+        // an input asked for by name has to be there
+        Command::Run { day, part, source } => {
+            let solver = solver::find(year, day).unwrap();
+            let mut ok = true;
+            for input in resolve(&inputs, day, &source).unwrap_or_else(|e| {
+                eprintln!("{}", e);
+                process::exit(2);
+            }) {
+                let contents = read_input(&input.path).unwrap_or_else(|e| {
+                    eprintln!("{}", e);
+                    process::exit(2);
+                });
+                ok &= report(&label(day, part, &input), run(solver, part, &contents));
+            }
+            ok
+        }
+        // days without an input file are skipped rather than failed
+        Command::All { source } => {
+            let mut ok = true;
+            for (day, part, solver) in parts(year) {
+                let resolved = resolve(&inputs, day, &source).unwrap_or_else(|e| {
+                    eprintln!("{}", e);
+                    process::exit(2);
+                });
+                for input in resolved {
+                    let label = label(day, part, &input);
+                    match read_input(&input.path) {
+                        Ok(contents) => ok &= report(&label, run(solver, part, &contents)),
+                        Err(e) => eprintln!("{}: skipped, {}", label, e),
+                    }
+                }
+            }
+            ok
+        }
+        // the input goes to stdout so it can be piped straight back in
+        Command::Generate { day, seed, size } => {
+            let puzzle = synth::generate(day, seed, size).unwrap();
+            print!("{}", puzzle.input);
+            for (part, answer) in [puzzle.part1, puzzle.part2].iter().enumerate() {
+                let answer = answer.as_deref().unwrap_or("unknown");
+                eprintln!("Day {} - Part {}: {}", day, part + 1, answer);
+            }
+            true
+        }
+        // missing inputs are skipped, failed steps are left out of the report
+        Command::Profile { day, source, json } => {
+            let (mut profiles, mut ok) = (vec![], true);
+            let solvers = solver::year(year).unwrap();
+            for (d, solver) in solvers
+                .iter()
+                .filter(|(d, _)| day.is_none() || day == Some(*d))
+            {
+                let resolved = resolve(&inputs, *d, &source).unwrap_or_else(|e| {
+                    eprintln!("{}", e);
+                    process::exit(2);
+                });
+                for input in resolved {
+                    let contents = match read_input(&input.path) {
+                        Ok(contents) => contents,
+                        Err(e) => {
+                            eprintln!("Day {}: skipped, {}", d, e);
+                            continue;
+                        }
+                    };
+                    let (measured, failures) = profile(year, *d, *solver, &input, &contents);
+                    for e in &failures {
+                        eprintln!("Day {}: FAILED while {}", d, e);
+                    }
+                    ok &= failures.is_empty();
+                    profiles.extend(measured);
+                }
+            }
+
+            if json {
+                print!("{}", profile::json(&profiles));
+            } else {
+                print!("{}", profile::table(&profiles));
+            }
+            ok
+        }
+        // the module goes in this crate's own source tree
+        Command::NewDay { day } => {
+            let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+            match scaffold::new_day(root, year, day) {
+                Ok(paths) => {
+                    for path in paths {
+                        println!("wrote {}", path.display());
+                    }
+                    true
+                }
+                Err(e) => {
+                    eprintln!("{}", e);
+                    process::exit(2);
+                }
+            }
+        }
+        // every solver against its recorded answers, missing inputs are skipped
+        Command::Verify { source, record } => {
+            let path = answers_path(&inputs);
+            let mut answers = Answers::load(&path).unwrap_or_else(|e| {
+                eprintln!("couldn't load {}: {}", path.display(), e);
+                process::exit(2);
+            });
+            let (mut passed, mut regressed, mut failed, mut unrecorded) = (0, 0, 0, vec![]);
+            for (day, part, solver) in parts(year) {
+                let resolved = resolve(&inputs, day, &source).unwrap_or_else(|e| {
+                    eprintln!("{}", e);
+                    process::exit(2);
+                });
+                for input in resolved {
+                    let label = label(day, part, &input);
+                    let contents = match read_input(&input.path) {
+                        Ok(contents) => contents,
+                        Err(e) => {
+                            eprintln!("{}: skipped, {}", label, e);
+                            continue;
+                        }
+                    };
+                    let name = input.name.as_deref();
+                    let verdict = match run(solver, part, &contents) {
+                        Ok(outcome) => answers.check(day, part, name, &outcome.answer),
+                        Err(e) => Verdict::Failed(e),
+                    };
+                    match &verdict {
+                        Verdict::Pass => passed += 1,
+                        Verdict::Regression { .. } => regressed += 1,
+                        Verdict::Failed(_) => failed += 1,
+                        Verdict::Unrecorded(answer) => {
+                            unrecorded.push((day, part, input.name.clone(), answer.clone()))
+                        }
+                    }
+                    if verdict.is_ok() {
+                        println!("{}: {}", label, verdict);
+                    } else {
+                        eprintln!("{}: {}", label, verdict);
+                    }
+                }
+            }
+
+            println!(
+                "{} passed, {} regressed, {} failed, {} unrecorded",
+                passed,
+                regressed,
+                failed,
+                unrecorded.len()
+            );
+            if record && !unrecorded.is_empty() {
+                let count = unrecorded.len();
+                for (day, part, name, answer) in unrecorded {
+                    answers.insert(day, part, name.as_deref(), answer);
+                }
+                answers.save(&path).unwrap_or_else(|e| {
+                    eprintln!("couldn't save {}: {}", path.display(), e);
+                    process::exit(2);
+                });
+                println!("recorded {} answers in {}", count, path.display());
+            }
+            regressed == 0 && failed == 0
+        }
+    };
+
+    if !ok {
+        process::exit(1);
+    }
+}
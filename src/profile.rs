@@ -0,0 +1,209 @@
+use serde_json::json;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+// an allocator that keeps count on top of the system one. a binary opts in
+// with #[global_allocator], without it every measurement reads as zero
+pub struct Counting {
+    current: AtomicUsize,
+    peak: AtomicUsize,
+    allocations: AtomicUsize,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Usage {
+    pub wall: Duration,
+    // the most that was allocated at once, over what was there beforehand
+    pub peak: usize,
+    pub allocations: usize,
+}
+
+impl Counting {
+    pub const fn new() -> Counting {
+        Counting {
+            current: AtomicUsize::new(0),
+            peak: AtomicUsize::new(0),
+            allocations: AtomicUsize::new(0),
+        }
+    }
+
+    fn grow(&self, size: usize) {
+        let current = self.current.fetch_add(size, Ordering::SeqCst) + size;
+        self.peak.fetch_max(current, Ordering::SeqCst);
+    }
+
+    fn shrink(&self, size: usize) {
+        self.current.fetch_sub(size, Ordering::SeqCst);
+    }
+
+    pub fn measure<T>(&self, f: impl FnOnce() -> T) -> (T, Usage) {
+        let before = self.current.load(Ordering::SeqCst);
+        self.peak.store(before, Ordering::SeqCst);
+        let allocations = self.allocations.load(Ordering::SeqCst);
+        let start = Instant::now();
+
+        let result = f();
+
+        let usage = Usage {
+            wall: start.elapsed(),
+            peak: self.peak.load(Ordering::SeqCst).saturating_sub(before),
+            allocations: self.allocations.load(Ordering::SeqCst) - allocations,
+        };
+        (result, usage)
+    }
+}
+
+impl Default for Counting {
+    fn default() -> Counting {
+        Counting::new()
+    }
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            self.allocations.fetch_add(1, Ordering::SeqCst);
+            self.grow(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        self.shrink(layout.size());
+    }
+
+    // a realloc counts as an allocation, it's usually a Vec outgrowing itself
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new = System.realloc(ptr, layout, new_size);
+        if !new.is_null() {
+            self.allocations.fetch_add(1, Ordering::SeqCst);
+            self.shrink(layout.size());
+            self.grow(new_size);
+        }
+        new
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Profile {
+    pub year: u32,
+    pub day: u32,
+    pub input: Option<String>,
+    // "generator", "part1" or "part2"
+    pub step: String,
+    pub usage: Usage,
+}
+
+fn bytes(n: usize) -> String {
+    let units = ["B", "KiB", "MiB", "GiB"];
+    let mut size = n as f64;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < units.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{} B", n)
+    } else {
+        format!("{:.1} {}", size, units[unit])
+    }
+}
+
+pub fn table(profiles: &[Profile]) -> String {
+    let mut table = format!(
+        "{:<5} {:<10} {:<10} {:>12} {:>12} {:>12}\n",
+        "day", "input", "step", "wall", "peak heap", "allocations"
+    );
+    for profile in profiles {
+        table += &format!(
+            "{:<5} {:<10} {:<10} {:>12} {:>12} {:>12}\n",
+            profile.day,
+            profile.input.as_deref().unwrap_or("-"),
+            profile.step,
+            format!("{:.2?}", profile.usage.wall),
+            bytes(profile.usage.peak),
+            profile.usage.allocations
+        );
+    }
+    table
+}
+
+pub fn json(profiles: &[Profile]) -> String {
+    let profiles: Vec<_> = profiles
+        .iter()
+        .map(|x| {
+            json!({
+                "year": x.year,
+                "day": x.day,
+                "input": x.input,
+                "step": x.step,
+                "wall_ns": x.usage.wall.as_nanos() as u64,
+                "peak_bytes": x.usage.peak,
+                "allocations": x.usage.allocations,
+            })
+        })
+        .collect();
+    serde_json::to_string_pretty(&profiles).unwrap() + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counting() {
+        let counting = Counting::new();
+        let small = Layout::from_size_align(100, 8).unwrap();
+        let large = Layout::from_size_align(4000, 8).unwrap();
+
+        let (_, usage) = counting.measure(|| unsafe {
+            let a = counting.alloc(small);
+            let b = counting.alloc(large);
+            counting.dealloc(b, large);
+            let a = counting.realloc(a, small, 200);
+            counting.dealloc(a, Layout::from_size_align(200, 8).unwrap());
+        });
+        assert_eq!(usage.peak, 4100);
+        assert_eq!(usage.allocations, 3);
+
+        // only what's allocated during the measurement counts
+        let kept = unsafe { counting.alloc(large) };
+        let (_, usage) = counting.measure(|| unsafe {
+            let a = counting.alloc(small);
+            counting.dealloc(a, small);
+        });
+        assert_eq!(usage.peak, 100);
+        assert_eq!(usage.allocations, 1);
+        unsafe { counting.dealloc(kept, large) };
+    }
+
+    #[test]
+    fn output() {
+        let profiles = [Profile {
+            year: 2020,
+            day: 8,
+            input: None,
+            step: "part2".to_owned(),
+            usage: Usage {
+                wall: Duration::from_micros(1500),
+                peak: 3 * 1024 + 512,
+                allocations: 42,
+            },
+        }];
+
+        let table = table(&profiles);
+        let row = table.lines().nth(1).unwrap();
+        assert!(row.starts_with("8     -          part2"));
+        assert!(row.contains("1.50ms"));
+        assert!(row.contains("3.5 KiB"));
+        assert!(row.ends_with("42"));
+
+        let json: serde_json::Value = serde_json::from_str(&json(&profiles)).unwrap();
+        assert_eq!(json[0]["wall_ns"], 1_500_000);
+        assert_eq!(json[0]["peak_bytes"], 3584);
+        assert_eq!(json[0]["input"], serde_json::Value::Null);
+    }
+}
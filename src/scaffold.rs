@@ -0,0 +1,296 @@
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+const TEMPLATE: &str = include_str!("stub.rs");
+
+// the year aoc_lib! in lib.rs is set up for, other years can't use the aoc
+// macros without clashing with it
+const AOC_LIB_YEAR: u32 = 2020;
+
+const YEARS: &str = "pub static YEARS: &[(u32, Solvers)] = &[";
+const SOLVERS: &str = "pub static SOLVERS: Solvers = &[";
+
+#[derive(Debug)]
+pub enum ScaffoldError {
+    Exists(PathBuf),
+    Io(io::Error),
+    Layout(PathBuf, String),
+}
+
+impl fmt::Display for ScaffoldError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ScaffoldError::Exists(path) => write!(f, "{} already exists", path.display()),
+            ScaffoldError::Io(e) => write!(f, "{}", e),
+            ScaffoldError::Layout(path, expected) => {
+                write!(f, "expected {} in {}", expected, path.display())
+            }
+        }
+    }
+}
+
+impl Error for ScaffoldError {}
+
+impl From<io::Error> for ScaffoldError {
+    fn from(e: io::Error) -> Self {
+        ScaffoldError::Io(e)
+    }
+}
+
+// the stub with its year and day filled in
+pub fn module(year: u32, day: u32) -> String {
+    let mut module = TEMPLATE.to_owned();
+    if year != AOC_LIB_YEAR {
+        module = module
+            .replace("{aoc_solver, Answer, Solver}", "{Answer, Solver}")
+            .replace("aoc_solver!(day11, Day11);\n\n", "");
+    }
+    module
+        .replace("/2020/", &format!("/{}/", year))
+        .replace("day11", &format!("day{}", day))
+        .replace("Day11", &format!("Day{}", day))
+}
+
+// adds `pub mod <name>;` to the block of them, which is kept in order
+fn add_mod(source: &str, name: &str) -> Option<String> {
+    let mut lines: Vec<String> = source.lines().map(|x| x.to_owned()).collect();
+    let in_block = |x: &String| x.starts_with("pub mod ") || x.starts_with("#[");
+    let first = lines.iter().position(in_block)?;
+    let count = lines[first..].iter().take_while(|x| in_block(x)).count();
+
+    // a mod keeps the attributes above it, like #[cfg(..)]
+    let mut block = vec![];
+    let mut attributes = vec![];
+    for line in lines.drain(first..first + count) {
+        let is_mod = line.starts_with("pub mod ");
+        attributes.push(line);
+        if is_mod {
+            block.push(std::mem::take(&mut attributes));
+        }
+    }
+    block.push(vec![format!("pub mod {};", name)]);
+    let key = |x: &Vec<String>| x.last().unwrap().trim_end_matches(';').to_owned();
+    block.sort_by_key(key);
+    lines.splice(first..first, block.into_iter().flatten());
+
+    Some(lines.join("\n") + "\n")
+}
+
+// adds a `(key, ...)` entry to the table starting at `start`, ordered by key
+// and laid out the way rustfmt would. entries stay on one line while they're
+// at most 60 wide, moving after the `=` if the line would be too long
+fn add_entry(source: &str, start: &str, entry: &str) -> Option<String> {
+    let from = source.find(start)?;
+    let to = from + source[from..].find("];")? + 2;
+
+    let mut entries: Vec<&str> = source[from + start.len()..to - 2]
+        .split(')')
+        .map(|x| x.trim_start_matches(|c: char| c == ',' || c.is_whitespace()))
+        .filter(|x| !x.is_empty())
+        .collect();
+    entries.push(entry.trim_end_matches(')'));
+    let key = |x: &&str| -> u32 {
+        x.trim_start_matches('(')
+            .split(',')
+            .next()
+            .and_then(|x| x.parse().ok())
+            .unwrap_or(0)
+    };
+    entries.sort_by_key(key);
+
+    let entries: Vec<String> = entries.iter().map(|x| format!("{})", x)).collect();
+    let line = entries.join(", ");
+    let (declaration, _) = start.split_at(start.len() - 3);
+    let table = if line.len() > 60 {
+        let mut table = start.to_owned();
+        for entry in entries {
+            table += &format!("\n    {},", entry);
+        }
+        table + "\n];"
+    } else if start.len() + line.len() + 2 <= 100 {
+        format!("{}{}];", start, line)
+    } else {
+        format!("{}\n    &[{}];", declaration, line)
+    };
+
+    Some(format!("{}{}{}", &source[..from], table, &source[to..]))
+}
+
+fn read(path: &Path) -> Result<String, ScaffoldError> {
+    Ok(fs::read_to_string(path)?)
+}
+
+fn layout(path: &Path, expected: &str) -> ScaffoldError {
+    ScaffoldError::Layout(path.to_owned(), expected.to_owned())
+}
+
+// writes src/y<year>/day<day>.rs and an empty example for it, then registers
+// it along with the year if it's a new one. every file's new contents are
+// worked out before anything is written, and if a write fails the files
+// already written are put back as they were
+pub fn new_day(root: &Path, year: u32, day: u32) -> Result<Vec<PathBuf>, ScaffoldError> {
+    let src = root.join("src");
+    let year_name = format!("y{}", year);
+    let day_name = format!("day{}", day);
+    let module_path = src.join(&year_name).join(format!("{}.rs", day_name));
+    if module_path.exists() {
+        return Err(ScaffoldError::Exists(module_path));
+    }
+
+    let mut changes = vec![];
+    let year_path = src.join(format!("{}.rs", year_name));
+    let entry = format!("({}, &{}::Day{})", day, day_name, day);
+    let year_source = if year_path.exists() {
+        add_mod(&read(&year_path)?, &day_name)
+            .and_then(|x| add_entry(&x, SOLVERS, &entry))
+            .ok_or_else(|| layout(&year_path, SOLVERS))?
+    } else {
+        let lib = src.join("lib.rs");
+        let source = add_mod(&read(&lib)?, &year_name).ok_or_else(|| layout(&lib, "pub mods"))?;
+        changes.push((lib, source));
+
+        let solver = src.join("solver.rs");
+        let years = format!("({}, crate::{}::SOLVERS)", year, year_name);
+        let source =
+            add_entry(&read(&solver)?, YEARS, &years).ok_or_else(|| layout(&solver, YEARS))?;
+        changes.push((solver, source));
+
+        format!(
+            "use crate::solver::Solvers;\n\npub mod {};\n\n{}{}];\n",
+            day_name, SOLVERS, entry
+        )
+    };
+    changes.push((year_path, year_source));
+    changes.push((module_path, module(year, day)));
+
+    // an example that's already there is kept
+    let fixtures = root
+        .join("tests/fixtures")
+        .join(year.to_string())
+        .join(&day_name);
+    for file in &["example.txt", "example.answers"] {
+        if !fixtures.join(file).exists() {
+            changes.push((fixtures.join(file), String::new()));
+        }
+    }
+
+    fs::create_dir_all(src.join(&year_name))?;
+    fs::create_dir_all(&fixtures)?;
+    let mut written: Vec<(PathBuf, Option<String>)> = vec![];
+    for (path, contents) in changes {
+        let before = fs::read_to_string(&path).ok();
+        if let Err(e) = fs::write(&path, contents) {
+            for (path, before) in written.into_iter().rev() {
+                let _ = match before {
+                    Some(before) => fs::write(&path, before),
+                    None => fs::remove_file(&path),
+                };
+            }
+            return Err(e.into());
+        }
+        written.push((path, before));
+    }
+    Ok(written.into_iter().map(|(path, _)| path).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+    use std::process;
+
+    #[test]
+    fn template() {
+        let module = module(2020, 14);
+        assert!(module.contains("aoc_solver!(day14, Day14);"));
+        assert!(module.contains("impl Solver for Day14"));
+        assert!(!module.contains("11"));
+
+        let module = self::module(2021, 3);
+        assert!(module.contains("use crate::solver::{Answer, Solver};"));
+        assert!(module.contains("\"../../tests/fixtures/2021/day3/example.txt\""));
+        assert!(!module.contains("aoc_solver"));
+    }
+
+    #[test]
+    fn tables() {
+        assert_eq!(
+            add_mod("use x;\n\npub mod a;\npub mod c;\n\nfn f() {}\n", "b").unwrap(),
+            "use x;\n\npub mod a;\npub mod b;\npub mod c;\n\nfn f() {}\n"
+        );
+        assert_eq!(
+            add_mod("pub mod a;\n#[cfg(test)]\npub mod c;\n", "b").unwrap(),
+            "pub mod a;\npub mod b;\n#[cfg(test)]\npub mod c;\n"
+        );
+        assert_eq!(add_mod("fn f() {}\n", "b"), None);
+
+        let table = "pub static SOLVERS: Solvers = &[(1, &day1::Day1)];\n";
+        assert_eq!(
+            add_entry(table, SOLVERS, "(3, &day3::Day3)").unwrap(),
+            "pub static SOLVERS: Solvers = &[(1, &day1::Day1), (3, &day3::Day3)];\n"
+        );
+        let table = add_entry(table, SOLVERS, "(12, &day12::Day12)").unwrap();
+        let table = add_entry(&table, SOLVERS, "(2, &day2::Day2)").unwrap();
+        assert_eq!(
+            table,
+            "pub static SOLVERS: Solvers = &[(1, &day1::Day1), (2, &day2::Day2), (12, &day12::Day12)];\n"
+        );
+        let years = "pub static YEARS: &[(u32, Solvers)] = &[(2020, crate::y2020::SOLVERS)];";
+        assert_eq!(
+            add_entry(years, YEARS, "(2021, crate::y2021::SOLVERS)").unwrap(),
+            "pub static YEARS: &[(u32, Solvers)] =\n    &[(2020, crate::y2020::SOLVERS), (2021, crate::y2021::SOLVERS)];"
+        );
+        assert_eq!(
+            add_entry(&table, SOLVERS, "(4, &day4::Day4)").unwrap(),
+            "pub static SOLVERS: Solvers = &[\n    (1, &day1::Day1),\n    (2, &day2::Day2),\n    (4, &day4::Day4),\n    (12, &day12::Day12),\n];\n"
+        );
+    }
+
+    #[test]
+    fn new_days() {
+        let root = env::temp_dir().join(format!("aoc-scaffold-{}", process::id()));
+        let src = root.join("src");
+        fs::create_dir_all(&src).unwrap();
+        fs::write(src.join("lib.rs"), include_str!("lib.rs")).unwrap();
+        fs::write(src.join("solver.rs"), include_str!("solver.rs")).unwrap();
+        fs::write(src.join("y2020.rs"), include_str!("y2020.rs")).unwrap();
+
+        // the day after the last one there is, so days added since don't matter
+        let last = crate::y2020::SOLVERS
+            .iter()
+            .map(|(day, _)| *day)
+            .max()
+            .unwrap();
+        let next = last + 1;
+        new_day(&root, 2020, next).unwrap();
+        let year = fs::read_to_string(src.join("y2020.rs")).unwrap();
+        assert!(year.contains(&format!("\npub mod day{};\n", next)));
+        assert!(year.contains(&format!(
+            "    ({}, &day{}::Day{}),\n    ({}, &day{}::Day{}),\n];",
+            last, last, last, next, next, next
+        )));
+        assert!(src.join(format!("y2020/day{}.rs", next)).is_file());
+        assert!(root
+            .join(format!("tests/fixtures/2020/day{}/example.txt", next))
+            .is_file());
+        assert!(matches!(
+            new_day(&root, 2020, next),
+            Err(ScaffoldError::Exists(_))
+        ));
+
+        new_day(&root, 2021, 1).unwrap();
+        let lib = fs::read_to_string(src.join("lib.rs")).unwrap();
+        assert!(lib.contains("pub mod y2020;\npub mod y2021;\n"));
+        let solver = fs::read_to_string(src.join("solver.rs")).unwrap();
+        assert!(solver.contains("(2020, crate::y2020::SOLVERS), (2021, crate::y2021::SOLVERS)];"));
+        assert_eq!(
+            fs::read_to_string(src.join("y2021.rs")).unwrap(),
+            "use crate::solver::Solvers;\n\npub mod day1;\n\npub static SOLVERS: Solvers = &[(1, &day1::Day1)];\n"
+        );
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,138 @@
+use std::error::Error;
+use std::fmt::Display;
+
+pub type Answer = Result<Box<dyn Display>, Box<dyn Error>>;
+
+// the parsed input may borrow from the text it was parsed from, so days with
+// string heavy inputs don't have to allocate for every token
+pub trait Solver: Sync {
+    type Input<'a>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, Box<dyn Error>>;
+    fn part1(&self, input: &Self::Input<'_>) -> Answer;
+    fn part2(&self, input: &Self::Input<'_>) -> Answer;
+}
+
+// an input together with the solver that parsed it
+pub trait Parsed {
+    fn solve(&self, part: u32) -> Answer;
+}
+
+struct WithSolver<'a, S: Solver> {
+    solver: &'a S,
+    input: S::Input<'a>,
+}
+
+impl<'a, S: Solver> Parsed for WithSolver<'a, S> {
+    fn solve(&self, part: u32) -> Answer {
+        match part {
+            1 => self.solver.part1(&self.input),
+            2 => self.solver.part2(&self.input),
+            _ => Err(format!("there is no part {}", part).into()),
+        }
+    }
+}
+
+// the object-safe side of a Solver, so days with different inputs can share
+// one registry
+pub trait AnySolver: Sync {
+    fn parse_any<'a>(&'a self, input: &'a str) -> Result<Box<dyn Parsed + 'a>, Box<dyn Error>>;
+}
+
+impl<S: Solver> AnySolver for S {
+    // cargo-aoc hands out the input without its trailing newline
+    fn parse_any<'a>(&'a self, input: &'a str) -> Result<Box<dyn Parsed + 'a>, Box<dyn Error>> {
+        let input = self.parse(input.trim_end_matches('\n'))?;
+        Ok(Box::new(WithSolver {
+            solver: self,
+            input,
+        }))
+    }
+}
+
+// a year's solvers by day
+pub type Solvers = &'static [(u32, &'static dyn AnySolver)];
+
+pub static YEARS: &[(u32, Solvers)] = &[(2020, crate::y2020::SOLVERS)];
+
+pub fn year(year: u32) -> Option<Solvers> {
+    YEARS
+        .iter()
+        .find(|(y, _)| *y == year)
+        .map(|(_, solvers)| *solvers)
+}
+
+pub fn find(year: u32, day: u32) -> Option<&'static dyn AnySolver> {
+    self::year(year)?
+        .iter()
+        .find(|(d, _)| *d == day)
+        .map(|(_, solver)| *solver)
+}
+
+// hooks a Solver up to cargo-aoc, whose macros only know about free functions.
+// its generators have to return owned values, so a day whose input borrows
+// from the text passes `borrowed` and each part parses for itself, which
+// cargo-aoc then times as part of the part
+macro_rules! aoc_solver {
+    ($day:ident, $solver:ident) => {
+        #[aoc_runner_derive::aoc_generator($day)]
+        fn generator(
+            input: &str,
+        ) -> Result<<$solver as Solver>::Input<'static>, Box<dyn std::error::Error>> {
+            $solver.parse(input)
+        }
+
+        #[aoc_runner_derive::aoc($day, part1)]
+        fn part1(
+            input: &<$solver as Solver>::Input<'static>,
+        ) -> Result<Box<dyn std::fmt::Display>, Box<dyn std::error::Error>> {
+            $solver.part1(input)
+        }
+
+        #[aoc_runner_derive::aoc($day, part2)]
+        fn part2(
+            input: &<$solver as Solver>::Input<'static>,
+        ) -> Result<Box<dyn std::fmt::Display>, Box<dyn std::error::Error>> {
+            $solver.part2(input)
+        }
+    };
+    ($day:ident, $solver:ident, borrowed) => {
+        #[aoc_runner_derive::aoc($day, part1)]
+        fn part1(input: &str) -> Result<Box<dyn std::fmt::Display>, Box<dyn std::error::Error>> {
+            $solver.part1(&$solver.parse(input)?)
+        }
+
+        #[aoc_runner_derive::aoc($day, part2)]
+        fn part2(input: &str) -> Result<Box<dyn std::fmt::Display>, Box<dyn std::error::Error>> {
+            $solver.part2(&$solver.parse(input)?)
+        }
+    };
+}
+
+pub(crate) use aoc_solver;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn registry() {
+        let solver = find(2020, 1).unwrap();
+        let input = solver
+            .parse_any("1721\n979\n366\n299\n675\n1456\n")
+            .unwrap();
+        assert_eq!(input.solve(1).unwrap().to_string(), "514579");
+        assert_eq!(input.solve(2).unwrap().to_string(), "241861950");
+        assert!(input.solve(3).is_err());
+
+        // new-day only adds to the year, so the days solved so far come first
+        let days: Vec<u32> = year(2020).unwrap().iter().map(|(day, _)| *day).collect();
+        let solved: Vec<u32> = (1..=13).collect();
+        assert_eq!(days[..solved.len()], solved[..]);
+        assert!(days.windows(2).all(|x| x[0] < x[1]));
+        let last = *days.last().unwrap();
+        assert!(solved.iter().all(|day| find(2020, *day).is_some()));
+        assert!(find(2020, last + 1).is_none());
+        assert!(find(1999, 1).is_none());
+    }
+}
@@ -1,26 +1,53 @@
-use aoc_runner_derive::{aoc, aoc_generator};
+use crate::solver::{aoc_solver, Answer, Solver};
+use crate::util::numbers;
+use std::error::Error;
 
-#[aoc_generator(day11)]
 fn input_generator(input: &str) -> Vec<i32> {
-    input.lines().map(|x| x.parse().unwrap()).collect()
+    numbers(input)
 }
 
-#[aoc(day11, part1)]
 fn solve_part1(input: &[i32]) -> i32 {
     todo!()
 }
 
-#[aoc(day11, part2)]
 fn solve_part2(input: &[i32]) -> u64 {
     todo!()
 }
 
+pub struct Day11;
+
+impl Solver for Day11 {
+    type Input<'a> = Vec<i32>;
+
+    fn parse(&self, input: &str) -> Result<Vec<i32>, Box<dyn Error>> {
+        Ok(input_generator(input))
+    }
+
+    fn part1(&self, input: &Vec<i32>) -> Answer {
+        Ok(Box::new(solve_part1(input)))
+    }
+
+    fn part2(&self, input: &Vec<i32>) -> Answer {
+        Ok(Box::new(solve_part2(input)))
+    }
+}
+
+aoc_solver!(day11, Day11);
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    // paste the example from the puzzle text in here, with its answers in
+    // example.answers next to it
+    const EXAMPLE: &str = include_str!("../../tests/fixtures/2020/day11/example.txt");
+
     #[test]
-    fn part1() {}
+    fn part1() {
+        assert_eq!(solve_part1(&input_generator(EXAMPLE)), 0);
+    }
     #[test]
-    fn part2() {}
+    fn part2() {
+        assert_eq!(solve_part2(&input_generator(EXAMPLE)), 0);
+    }
 }
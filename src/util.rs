@@ -0,0 +1,10 @@
+use std::fmt::Debug;
+use std::str::FromStr;
+
+// one number per line, about the most common input there is
+pub fn numbers<T: FromStr>(input: &str) -> Vec<T>
+where
+    T::Err: Debug,
+{
+    input.lines().map(|x| x.parse().unwrap()).collect()
+}
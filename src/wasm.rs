@@ -0,0 +1,25 @@
+// the registry for the browser. `wasm-pack build --target web --out-dir www/pkg`
+// builds it for www/index.html
+use crate::solver;
+use wasm_bindgen::prelude::*;
+
+const YEAR: u32 = 2020;
+
+#[wasm_bindgen]
+pub fn days() -> Vec<u32> {
+    solver::year(YEAR)
+        .unwrap_or(&[])
+        .iter()
+        .map(|(day, _)| *day)
+        .collect()
+}
+
+// there's no catch_unwind on wasm, a solver that panics traps and the page
+// sees an exception instead of an Err
+#[wasm_bindgen]
+pub fn solve(day: u32, part: u32, input: &str) -> Result<String, String> {
+    let solver = solver::find(YEAR, day).ok_or(format!("no solution for day {}", day))?;
+    let parsed = solver.parse_any(input).map_err(|e| e.to_string())?;
+    let answer = parsed.solve(part).map_err(|e| e.to_string())?;
+    Ok(answer.to_string())
+}
@@ -0,0 +1,148 @@
+use crate::solver::{aoc_solver, Answer, Solver};
+use crate::util::numbers;
+use std::collections::HashSet;
+use std::error::Error;
+
+pub fn input_generator(input: &str) -> Vec<u32> {
+    numbers(input)
+}
+
+// only looks back at the entries before x, so an entry never pairs with itself
+fn find_target(input: &[u32], num: u32) -> Option<u32> {
+    let mut seen = HashSet::new();
+    input
+        .iter()
+        .filter_map(|x| {
+            let product = num
+                .checked_sub(*x)
+                .and_then(|diff| seen.get(&diff))
+                .map(|y| x * y);
+            seen.insert(*x);
+            product
+        })
+        .next()
+}
+
+pub fn solve_part1(input: &[u32]) -> u32 {
+    find_target(input, 2020).unwrap()
+}
+
+pub fn solve_part2(input: &[u32]) -> u32 {
+    input
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, x)| {
+            2020u32
+                .checked_sub(*x)
+                .and_then(|rest| find_target(&input[idx + 1..], rest))
+                .map(|prod| prod * x)
+        })
+        .next()
+        .unwrap()
+}
+
+pub struct Day1;
+
+impl Solver for Day1 {
+    type Input<'a> = Vec<u32>;
+
+    fn parse(&self, input: &str) -> Result<Vec<u32>, Box<dyn Error>> {
+        Ok(input_generator(input))
+    }
+
+    fn part1(&self, input: &Vec<u32>) -> Answer {
+        Ok(Box::new(solve_part1(input)))
+    }
+
+    fn part2(&self, input: &Vec<u32>) -> Answer {
+        Ok(Box::new(solve_part2(input)))
+    }
+}
+
+aoc_solver!(day1, Day1);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(solve_part1(&[1721, 979, 366, 299, 675, 1456]), 514579);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(solve_part2(&[1721, 979, 366, 299, 675, 1456]), 241861950);
+    }
+
+    #[test]
+    fn distinct_entries() {
+        assert_eq!(find_target(&[1010, 3], 2020), None);
+        assert_eq!(find_target(&[1010, 3, 1010], 2020), Some(1020100));
+        assert_eq!(solve_part2(&[1000, 20, 1000, 10]), 20000000);
+    }
+
+    // every product of entries at different positions that sum to 2020, so a
+    // lone 1010 doesn't pair with itself
+    fn naive_pairs(input: &[u32]) -> Vec<u32> {
+        let mut products = vec![];
+        for (i, x) in input.iter().enumerate() {
+            for y in &input[i + 1..] {
+                if x + y == 2020 {
+                    products.push(x * y);
+                }
+            }
+        }
+        products
+    }
+
+    fn naive_triples(input: &[u32]) -> Vec<u32> {
+        let mut products = vec![];
+        for (i, x) in input.iter().enumerate() {
+            for (j, y) in input.iter().enumerate().skip(i + 1) {
+                for z in &input[j + 1..] {
+                    if x + y + z == 2020 {
+                        products.push(x * y * z);
+                    }
+                }
+            }
+        }
+        products
+    }
+
+    fn entries() -> impl Strategy<Value = Vec<u32>> {
+        // 1010 and friends turn up often enough to catch an entry being reused
+        prop::collection::vec(
+            prop_oneof![0..=2020u32, Just(1010), Just(1000), Just(20)],
+            0..40,
+        )
+    }
+
+    // part 2 panics without an answer, so one is always planted
+    fn with_triple() -> impl Strategy<Value = Vec<u32>> {
+        (entries(), 0..=2020u32, 0..=2020u32)
+            .prop_map(|(mut entries, x, y)| {
+                let (x, y) = (x.min(y), x.max(y) - x.min(y));
+                entries.extend(&[x, y, 2020 - x - y]);
+                entries
+            })
+            .prop_shuffle()
+    }
+
+    proptest! {
+        #[test]
+        fn pair_matches_naive(input in entries()) {
+            let naive = naive_pairs(&input);
+            match find_target(&input, 2020) {
+                Some(product) => prop_assert!(naive.contains(&product)),
+                None => prop_assert!(naive.is_empty()),
+            }
+        }
+
+        #[test]
+        fn triple_matches_naive(input in with_triple()) {
+            prop_assert!(naive_triples(&input).contains(&solve_part2(&input)));
+        }
+    }
+}
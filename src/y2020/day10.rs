@@ -0,0 +1,463 @@
+use crate::count::{Count, Counter, Overflow};
+use crate::solver::{aoc_solver, Answer, Solver};
+use crate::util::numbers;
+use rand::Rng;
+use std::error::Error;
+use std::fmt;
+
+fn input_generator(input: &str) -> Vec<i32> {
+    numbers(input)
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct AdapterChain {
+    pub max_gap: i32,
+    pub wall: i32,
+    pub device_offset: i32,
+}
+
+impl Default for AdapterChain {
+    fn default() -> Self {
+        AdapterChain {
+            max_gap: 3,
+            wall: 0,
+            device_offset: 3,
+        }
+    }
+}
+
+// the gap histogram has a slot for every gap up to max_gap
+const LARGEST_GAP: i32 = 1 << 16;
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum ChainError {
+    Gap { from: i32, to: i32 },
+    MaxGap(i32),
+    OutOfRange,
+    Overflow,
+}
+
+impl fmt::Display for ChainError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ChainError::Gap { from, to } => write!(f, "can't connect {}J to {}J", from, to),
+            ChainError::MaxGap(gap) => {
+                write!(f, "max gap {}J isn't between 0 and {}J", gap, LARGEST_GAP)
+            }
+            ChainError::OutOfRange => write!(f, "device joltage out of range"),
+            ChainError::Overflow => write!(f, "{}", Overflow),
+        }
+    }
+}
+
+impl Error for ChainError {}
+
+impl From<Overflow> for ChainError {
+    fn from(_: Overflow) -> Self {
+        ChainError::Overflow
+    }
+}
+
+// whether to is at most max_gap above from, for joltages in ascending order
+fn within(from: i32, to: i32, max_gap: i32) -> bool {
+    to <= from.saturating_add(max_gap)
+}
+
+impl AdapterChain {
+    pub fn device(&self, adapters: &[i32]) -> Result<i32, ChainError> {
+        adapters
+            .iter()
+            .cloned()
+            .max()
+            .unwrap_or(self.wall)
+            .checked_add(self.device_offset)
+            .ok_or(ChainError::OutOfRange)
+    }
+
+    // the wall, every adapter in ascending order and then the device
+    fn joltages(&self, adapters: &[i32]) -> Result<Vec<i32>, ChainError> {
+        if !(0..=LARGEST_GAP).contains(&self.max_gap) {
+            return Err(ChainError::MaxGap(self.max_gap));
+        }
+
+        let mut adapters = adapters.to_owned();
+        adapters.sort();
+        let device = self.device(&adapters)?;
+
+        Ok(std::iter::once(self.wall)
+            .chain(adapters)
+            .chain(std::iter::once(device))
+            .collect())
+    }
+
+    fn gap(&self, from: i32, to: i32) -> Result<i32, ChainError> {
+        match to.checked_sub(from) {
+            Some(gap) if 1 <= gap && gap <= self.max_gap => Ok(gap),
+            _ => Err(ChainError::Gap { from, to }),
+        }
+    }
+
+    pub fn gaps(&self, adapters: &[i32]) -> Result<Vec<usize>, ChainError> {
+        let joltages = self.joltages(adapters)?;
+        let mut histogram = vec![0; self.max_gap as usize + 1];
+
+        for pair in joltages.windows(2) {
+            histogram[self.gap(pair[0], pair[1])? as usize] += 1;
+        }
+
+        Ok(histogram)
+    }
+
+    // for every joltage, the number of ways to get from it to the device.
+    // the chain has to be valid the same way gaps checks it
+    fn completions<C: Count>(&self, joltages: &[i32]) -> Result<Vec<C>, ChainError> {
+        for pair in joltages.windows(2) {
+            self.gap(pair[0], pair[1])?;
+        }
+
+        let mut cache = vec![C::zero(); joltages.len()];
+        cache[joltages.len() - 1] = C::one();
+
+        for (idx, num) in joltages.iter().enumerate().rev().skip(1) {
+            cache[idx] = C::sum(
+                joltages[idx + 1..]
+                    .iter()
+                    .zip(&cache[idx + 1..])
+                    .take_while(|(x, _)| within(*num, **x, self.max_gap))
+                    .map(|(_, cached)| cached),
+            )?;
+        }
+
+        Ok(cache)
+    }
+
+    pub fn arrangements<C: Count>(&self, adapters: &[i32]) -> Result<C, ChainError> {
+        let mut cache = self.completions(&self.joltages(adapters)?)?;
+        Ok(cache.swap_remove(0))
+    }
+
+    // every valid arrangement of the adapters, in lexicographic order. ranks
+    // are u128 even with the bigint feature, so a chain with more arrangements
+    // than that fails with ChainError::Overflow here and in nth_arrangement
+    // and sample_arrangement, while arrangements::<Counter> still counts them
+    pub fn iter_arrangements(&self, adapters: &[i32]) -> Result<Arrangements, ChainError> {
+        let joltages = self.joltages(adapters)?;
+        let counts = self.completions(&joltages)?;
+
+        Ok(Arrangements {
+            max_gap: self.max_gap,
+            next: 0,
+            total: counts[0],
+            joltages,
+            counts,
+        })
+    }
+
+    pub fn nth_arrangement(
+        &self,
+        adapters: &[i32],
+        rank: u128,
+    ) -> Result<Option<Vec<i32>>, ChainError> {
+        Ok(self.iter_arrangements(adapters)?.unrank(rank))
+    }
+
+    pub fn sample_arrangement<R: Rng + ?Sized>(
+        &self,
+        adapters: &[i32],
+        rng: &mut R,
+    ) -> Result<Vec<i32>, ChainError> {
+        // iter_arrangements fails on any chain that can't be completed, so
+        // there's always at least one arrangement to pick
+        let arrangements = self.iter_arrangements(adapters)?;
+        let rank = rng.gen_range(0..arrangements.total);
+        Ok(arrangements.unrank(rank).unwrap())
+    }
+}
+
+pub struct Arrangements {
+    max_gap: i32,
+    joltages: Vec<i32>,
+    counts: Vec<u128>,
+    next: u128,
+    total: u128,
+}
+
+impl Arrangements {
+    // stopping at the device sorts before continuing to any further adapter,
+    // so it is tried first and the remaining options in ascending order. the
+    // joltages are sorted, so the options end at the first one out of reach
+    fn options(&self, idx: usize) -> impl Iterator<Item = usize> + '_ {
+        let device = self.joltages.len() - 1;
+        let reachable =
+            move |next: &usize| within(self.joltages[idx], self.joltages[*next], self.max_gap);
+
+        std::iter::once(device)
+            .filter(reachable)
+            .chain((idx + 1..device).take_while(reachable))
+    }
+
+    fn unrank(&self, mut rank: u128) -> Option<Vec<i32>> {
+        if rank >= self.total {
+            return None;
+        }
+
+        let device = self.joltages.len() - 1;
+        let mut arrangement = vec![];
+        let mut idx = 0;
+
+        while idx != device {
+            for next in self.options(idx) {
+                if rank < self.counts[next] {
+                    idx = next;
+                    break;
+                }
+                rank -= self.counts[next];
+            }
+            if idx != device {
+                arrangement.push(self.joltages[idx]);
+            }
+        }
+
+        Some(arrangement)
+    }
+
+    pub fn total(&self) -> u128 {
+        self.total
+    }
+}
+
+impl Iterator for Arrangements {
+    type Item = Vec<i32>;
+
+    fn next(&mut self) -> Option<Vec<i32>> {
+        let arrangement = self.unrank(self.next)?;
+        self.next += 1;
+        Some(arrangement)
+    }
+}
+
+fn count_gaps(input: &[i32]) -> Result<(usize, usize), ChainError> {
+    let gaps = AdapterChain::default().gaps(input)?;
+    Ok((gaps[1], gaps[3]))
+}
+
+fn solve_part1(input: &[i32]) -> Result<usize, ChainError> {
+    let (ones, threes) = count_gaps(input)?;
+    Ok(ones * threes)
+}
+
+fn solve_part2(input: &[i32]) -> Result<Counter, ChainError> {
+    AdapterChain::default().arrangements(input)
+}
+
+pub struct Day10;
+
+impl Solver for Day10 {
+    type Input<'a> = Vec<i32>;
+
+    fn parse(&self, input: &str) -> Result<Vec<i32>, Box<dyn Error>> {
+        Ok(input_generator(input))
+    }
+
+    fn part1(&self, input: &Vec<i32>) -> Answer {
+        Ok(Box::new(solve_part1(input)?))
+    }
+
+    fn part2(&self, input: &Vec<i32>) -> Answer {
+        Ok(Box::new(solve_part2(input)?))
+    }
+}
+
+aoc_solver!(day10, Day10);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    #[test]
+    fn part1_example1() {
+        assert_eq!(
+            count_gaps(&[16, 10, 15, 5, 1, 11, 7, 19, 6, 12, 4]),
+            Ok((7, 5))
+        );
+    }
+    #[test]
+    fn part1_example2() {
+        assert_eq!(
+            count_gaps(&[
+                28, 33, 18, 42, 31, 14, 46, 20, 48, 47, 24, 23, 49, 45, 19, 38, 39, 11, 1, 32, 25,
+                35, 8, 17, 7, 9, 4, 2, 34, 10, 3,
+            ]),
+            Ok((22, 10))
+        );
+    }
+    #[test]
+    fn part2_example1() {
+        assert_eq!(
+            solve_part2(&[16, 10, 15, 5, 1, 11, 7, 19, 6, 12, 4]).unwrap(),
+            Counter::from_usize(8)
+        );
+    }
+    #[test]
+    fn part2_example2() {
+        assert_eq!(
+            solve_part2(&[
+                28, 33, 18, 42, 31, 14, 46, 20, 48, 47, 24, 23, 49, 45, 19, 38, 39, 11, 1, 32, 25,
+                35, 8, 17, 7, 9, 4, 2, 34, 10, 3,
+            ])
+            .unwrap(),
+            Counter::from_usize(19208)
+        );
+    }
+    #[test]
+    fn part2_overflow() {
+        let input: Vec<i32> = (1..=100).collect();
+
+        assert_eq!(
+            AdapterChain::default().arrangements::<u64>(&input),
+            Err(ChainError::Overflow)
+        );
+        assert_eq!(
+            AdapterChain::default().arrangements::<u128>(&input),
+            Ok(180396380815100901214157639)
+        );
+    }
+    #[test]
+    fn gap_histogram() {
+        assert_eq!(
+            AdapterChain::default().gaps(&[3, 1, 4]),
+            Ok(vec![0, 2, 1, 1])
+        );
+        assert_eq!(
+            AdapterChain::default().gaps(&[1, 5]),
+            Err(ChainError::Gap { from: 1, to: 5 })
+        );
+        assert_eq!(
+            AdapterChain::default().gaps(&[1, 1]),
+            Err(ChainError::Gap { from: 1, to: 1 })
+        );
+    }
+    #[test]
+    fn duplicate_adapters() {
+        let chain = AdapterChain::default();
+
+        assert_eq!(
+            chain.arrangements::<u64>(&[1, 2, 2, 3]),
+            Err(ChainError::Gap { from: 2, to: 2 })
+        );
+        assert!(chain.iter_arrangements(&[1, 1]).is_err());
+    }
+    #[test]
+    fn custom_chain() {
+        let chain = AdapterChain {
+            max_gap: 2,
+            wall: 10,
+            device_offset: 1,
+        };
+
+        assert_eq!(chain.gaps(&[11, 12, 13]), Ok(vec![0, 4, 0]));
+        assert_eq!(chain.arrangements::<u64>(&[11, 12, 13]), Ok(5));
+        assert_eq!(
+            chain.arrangements::<u64>(&[11, 14]),
+            Err(ChainError::Gap { from: 11, to: 14 })
+        );
+    }
+    #[test]
+    fn extreme_chains() {
+        let chain = |max_gap, wall, device_offset| AdapterChain {
+            max_gap,
+            wall,
+            device_offset,
+        };
+
+        assert_eq!(chain(-1, 0, 3).gaps(&[1]), Err(ChainError::MaxGap(-1)));
+        assert_eq!(
+            chain(i32::MAX, 0, 3).gaps(&[1]),
+            Err(ChainError::MaxGap(i32::MAX))
+        );
+        assert_eq!(
+            chain(3, 0, i32::MAX).arrangements::<u64>(&[1]),
+            Err(ChainError::OutOfRange)
+        );
+        assert_eq!(
+            chain(3, i32::MIN, 3).gaps(&[i32::MAX - 3]),
+            Err(ChainError::Gap {
+                from: i32::MIN,
+                to: i32::MAX - 3
+            })
+        );
+
+        let top = chain(3, i32::MAX - 3, 2);
+        assert_eq!(top.gaps(&[i32::MAX - 2]), Ok(vec![0, 1, 1, 0]));
+        assert_eq!(top.arrangements::<u64>(&[i32::MAX - 2]), Ok(2));
+        assert_eq!(top.iter_arrangements(&[i32::MAX - 2]).unwrap().count(), 2);
+    }
+    #[test]
+    fn enumerate_arrangements() {
+        let input = [16, 10, 15, 5, 1, 11, 7, 19, 6, 12, 4];
+        let chain = AdapterChain::default();
+        let all: Vec<_> = chain.iter_arrangements(&input).unwrap().collect();
+
+        assert_eq!(all.len(), 8);
+        assert!(all.windows(2).all(|x| x[0] < x[1]));
+        assert_eq!(all[0], vec![1, 4, 5, 6, 7, 10, 11, 12, 15, 16, 19]);
+        assert_eq!(all[7], vec![1, 4, 7, 10, 12, 15, 16, 19]);
+        for (rank, arrangement) in all.iter().enumerate() {
+            assert!(chain.gaps(arrangement).is_ok());
+            assert_eq!(
+                chain
+                    .nth_arrangement(&input, rank as u128)
+                    .unwrap()
+                    .as_ref(),
+                Some(arrangement)
+            );
+        }
+        assert_eq!(chain.nth_arrangement(&input, 8), Ok(None));
+    }
+    #[test]
+    fn long_chain() {
+        let input: Vec<i32> = (1..=100_000).map(|x| x * 3).collect();
+        let chain = AdapterChain::default();
+        let mut rng = StdRng::seed_from_u64(10);
+
+        assert_eq!(chain.nth_arrangement(&input, 0), Ok(Some(input.clone())));
+        assert_eq!(
+            chain.sample_arrangement(&input, &mut rng),
+            Ok(input.clone())
+        );
+        assert_eq!(chain.iter_arrangements(&input).unwrap().count(), 1);
+    }
+    #[test]
+    fn sample_arrangements() {
+        let input = [16, 10, 15, 5, 1, 11, 7, 19, 6, 12, 4];
+        let chain = AdapterChain::default();
+        let all: Vec<_> = chain.iter_arrangements(&input).unwrap().collect();
+        let mut rng = StdRng::seed_from_u64(10);
+        let mut seen = vec![0; all.len()];
+
+        for _ in 0..400 {
+            let sample = chain.sample_arrangement(&input, &mut rng).unwrap();
+            seen[all.iter().position(|x| *x == sample).unwrap()] += 1;
+        }
+
+        assert!(seen.iter().all(|x| *x > 20));
+
+        let chain = AdapterChain {
+            max_gap: 0,
+            ..AdapterChain::default()
+        };
+        assert_eq!(
+            chain.sample_arrangement(&input, &mut rng),
+            Err(ChainError::Gap { from: 0, to: 1 })
+        );
+        let chain = AdapterChain {
+            device_offset: 4,
+            ..AdapterChain::default()
+        };
+        assert_eq!(
+            chain.sample_arrangement(&input, &mut rng),
+            Err(ChainError::Gap { from: 19, to: 23 })
+        );
+    }
+}
@@ -1,15 +1,15 @@
-use aoc_runner_derive::{aoc, aoc_generator};
+use crate::solver::{aoc_solver, Answer, Solver};
 use core::convert::TryInto;
+use std::error::Error;
 use vec2d::{Coord, Size, Vec2D};
 
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
-enum Seat {
+pub enum Seat {
     Floor,
     Empty,
     Occupied,
 }
 
-#[aoc_generator(day11)]
 fn input_generator(input: &str) -> Vec2D<Seat> {
     let mut width = None;
 
@@ -167,7 +167,6 @@ fn count_occupied(i: impl Iterator<Item = Seat>) -> usize {
     .sum()
 }
 
-#[aoc(day11, part1)]
 fn solve_part1(input: &Vec2D<Seat>) -> usize {
     count_occupied(
         run_till_stable(input, count_neighbors, transition_1)
@@ -176,7 +175,6 @@ fn solve_part1(input: &Vec2D<Seat>) -> usize {
     )
 }
 
-#[aoc(day11, part2)]
 fn solve_part2(input: &Vec2D<Seat>) -> usize {
     count_occupied(
         run_till_stable(input, count_sights, transition_2)
@@ -185,10 +183,32 @@ fn solve_part2(input: &Vec2D<Seat>) -> usize {
     )
 }
 
+pub struct Day11;
+
+impl Solver for Day11 {
+    type Input<'a> = Vec2D<Seat>;
+
+    fn parse(&self, input: &str) -> Result<Vec2D<Seat>, Box<dyn Error>> {
+        Ok(input_generator(input))
+    }
+
+    fn part1(&self, input: &Vec2D<Seat>) -> Answer {
+        Ok(Box::new(solve_part1(input)))
+    }
+
+    fn part2(&self, input: &Vec2D<Seat>) -> Answer {
+        Ok(Box::new(solve_part2(input)))
+    }
+}
+
+aoc_solver!(day11, Day11);
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    const EXAMPLE: &str = include_str!("../../tests/fixtures/2020/day11/example.txt");
+
     #[test]
     fn test_count_neighbors_1() {
         let a = input_generator(
@@ -220,20 +240,9 @@ L#LLL",
         assert_eq!(res, b);
     }
 
-    #[test]
-    fn part1_step_1() {
-        assert_steps_to(
-            "L.LL.LL.LL
-LLLLLLL.LL
-L.L.L..L..
-LLLL.LL.LL
-L.LL.LL.LL
-L.LLLLL.LL
-..L.L.....
-LLLLLLLLLL
-L.LLLLLL.L
-L.LLLLL.LL",
-            "#.##.##.##
+    // the layout after each round of the part 1 rules, ending once nothing changes
+    const PART1_ROUNDS: &[&str] = &[
+        "#.##.##.##
 #######.##
 #.#.#..#..
 ####.##.##
@@ -243,39 +252,7 @@ L.LLLLL.LL",
 ##########
 #.######.#
 #.#####.##",
-        );
-    }
-
-    #[test]
-    fn part1_step_2() {
-        assert_steps_to(
-            "#.##.##.##
-#######.##
-#.#.#..#..
-####.##.##
-#.##.##.##
-#.#####.##
-..#.#.....
-##########
-#.######.#
-#.#####.##",
-            "#.LL.L#.##
-#LLLLLL.L#
-L.L.L..L..
-#LLL.LL.L#
-#.LL.LL.LL
-#.LLLL#.##
-..L.L.....
-#LLLLLLLL#
-#.LLLLLL.L
-#.#LLLL.##
-",
-        );
-    }
-    #[test]
-    fn part1_step_4() {
-        assert_steps_to(
-            "#.LL.L#.##
+        "#.LL.L#.##
 #LLLLLL.L#
 L.L.L..L..
 #LLL.LL.L#
@@ -285,22 +262,7 @@ L.L.L..L..
 #LLLLLLLL#
 #.LLLLLL.L
 #.#LLLL.##",
-            "#.##.L#.##
-#L###LL.L#
-L.#.#..#..
-#L##.##.L#
-#.##.LL.LL
-#.###L#.##
-..#.#.....
-#L######L#
-#.LL###L.L
-#.#L###.##",
-        );
-    }
-    #[test]
-    fn part1_step_5() {
-        assert_steps_to(
-            "#.##.L#.##
+        "#.##.L#.##
 #L###LL.L#
 L.#.#..#..
 #L##.##.L#
@@ -310,23 +272,7 @@ L.#.#..#..
 #L######L#
 #.LL###L.L
 #.#L###.##",
-            "#.#L.L#.##
-#LLL#LL.L#
-L.L.L..#..
-#LLL.##.L#
-#.LL.LL.LL
-#.LL#L#.##
-..L.L.....
-#L#LLLL#L#
-#.LLLLLL.L
-#.#L#L#.##
-",
-        );
-    }
-    #[test]
-    fn part1_step_6() {
-        assert_steps_to(
-            "#.#L.L#.##
+        "#.#L.L#.##
 #LLL#LL.L#
 L.L.L..#..
 #LLL.##.L#
@@ -336,22 +282,7 @@ L.L.L..#..
 #L#LLLL#L#
 #.LLLLLL.L
 #.#L#L#.##",
-            "#.#L.L#.##
-#LLL#LL.L#
-L.#.L..#..
-#L##.##.L#
-#.#L.LL.LL
-#.#L#L#.##
-..L.L.....
-#L#L##L#L#
-#.LLLLLL.L
-#.#L#L#.##",
-        );
-    }
-    #[test]
-    fn part1_step_7() {
-        assert_steps_to(
-            "#.#L.L#.##
+        "#.#L.L#.##
 #LLL#LL.L#
 L.#.L..#..
 #L##.##.L#
@@ -361,7 +292,7 @@ L.#.L..#..
 #L#L##L#L#
 #.LLLLLL.L
 #.#L#L#.##",
-            "#.#L.L#.##
+        "#.#L.L#.##
 #LLL#LL.L#
 L.#.L..#..
 #L##.##.L#
@@ -371,50 +302,28 @@ L.#.L..#..
 #L#L##L#L#
 #.LLLLLL.L
 #.#L#L#.##",
-        );
+    ];
+
+    #[test]
+    fn part1_steps() {
+        let mut layouts = vec![EXAMPLE];
+        layouts.extend(PART1_ROUNDS);
+
+        for (a, b) in layouts.iter().zip(layouts.iter().skip(1)) {
+            assert_steps_to(a, b);
+        }
     }
     #[test]
     fn part1() {
-        assert_eq!(
-            solve_part1(&input_generator(
-                "L.LL.LL.LL
-LLLLLLL.LL
-L.L.L..L..
-LLLL.LL.LL
-L.LL.LL.LL
-L.LLLLL.LL
-..L.L.....
-LLLLLLLLLL
-L.LLLLLL.L
-L.LLLLL.LL"
-            )),
-            37
-        );
+        assert_eq!(solve_part1(&input_generator(EXAMPLE)), 37);
     }
 
     #[test]
     fn part_2() {
         let inputs = [
-            "L.LL.LL.LL
-LLLLLLL.LL
-L.L.L..L..
-LLLL.LL.LL
-L.LL.LL.LL
-L.LLLLL.LL
-..L.L.....
-LLLLLLLLLL
-L.LLLLLL.L
-L.LLLLL.LL",
-            "#.##.##.##
-#######.##
-#.#.#..#..
-####.##.##
-#.##.##.##
-#.#####.##
-..#.#.....
-##########
-#.######.#
-#.#####.##",
+            EXAMPLE,
+            // everyone sits down in the first round under either set of rules
+            PART1_ROUNDS[0],
             "#.LL.LL.L#
 #LLLLLL.LL
 L.L.L..L..
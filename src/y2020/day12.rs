@@ -0,0 +1,544 @@
+use crate::solver::{aoc_solver, Answer, Solver};
+use std::error::Error;
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Neg};
+
+pub mod export;
+pub mod plan;
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Instruction {
+    Direction(Direction),
+    Rotation(Rotation),
+    F,
+    B,
+    Reverse,
+    Reset,
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Direction {
+    N,
+    S,
+    E,
+    W,
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Rotation {
+    L,
+    R,
+}
+
+use Direction::*;
+use Rotation::*;
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum ParseError {
+    UnknownInstruction { line: usize, instruction: char },
+    BadAmount { line: usize, amount: String },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseError::UnknownInstruction { line, instruction } => {
+                write!(f, "line {}: unknown instruction {:?}", line, instruction)
+            }
+            ParseError::BadAmount { line, amount } => {
+                write!(f, "line {}: bad amount {:?}", line, amount)
+            }
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+// on top of the puzzle's NSEWLRF, B moves backwards, U turns around and Z
+// resets the heading and waypoint. U and Z don't need an amount.
+pub fn input_generator(input: &str) -> Result<Vec<(Instruction, i32)>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, x)| !x.trim().is_empty())
+        .map(|(idx, x)| {
+            let x = x.trim();
+            let letter = x.chars().next().unwrap();
+            let amount = &x[letter.len_utf8()..];
+            let instruction = match letter {
+                'N' => Instruction::Direction(N),
+                'S' => Instruction::Direction(S),
+                'E' => Instruction::Direction(E),
+                'W' => Instruction::Direction(W),
+                'L' => Instruction::Rotation(L),
+                'R' => Instruction::Rotation(R),
+                'F' => Instruction::F,
+                'B' => Instruction::B,
+                'U' => Instruction::Reverse,
+                'Z' => Instruction::Reset,
+                instruction => {
+                    return Err(ParseError::UnknownInstruction {
+                        line: idx + 1,
+                        instruction,
+                    })
+                }
+            };
+            let amount = match instruction {
+                Instruction::Reverse | Instruction::Reset if amount.is_empty() => Ok(0),
+                _ => amount.parse(),
+            }
+            .map_err(|_| ParseError::BadAmount {
+                line: idx + 1,
+                amount: amount.to_owned(),
+            })?;
+
+            Ok((instruction, amount))
+        })
+        .collect()
+}
+
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct Vec2i(pub i32, pub i32);
+
+impl Vec2i {
+    // counterclockwise, a negative number of turns goes clockwise
+    pub fn rotate_left(self, quarter_turns: i32) -> Vec2i {
+        let Vec2i(n, e) = self;
+        match quarter_turns.rem_euclid(4) {
+            0 => Vec2i(n, e),
+            1 => Vec2i(e, -n),
+            2 => Vec2i(-n, -e),
+            _ => Vec2i(-e, n),
+        }
+    }
+
+    pub fn manhattan(self) -> i32 {
+        self.0.abs() + self.1.abs()
+    }
+
+    pub fn euclidean(self) -> f64 {
+        f64::from(self.0).hypot(f64::from(self.1))
+    }
+}
+
+impl Add for Vec2i {
+    type Output = Vec2i;
+
+    fn add(self, other: Vec2i) -> Vec2i {
+        Vec2i(self.0 + other.0, self.1 + other.1)
+    }
+}
+
+impl AddAssign for Vec2i {
+    fn add_assign(&mut self, other: Vec2i) {
+        *self = *self + other;
+    }
+}
+
+impl Mul<i32> for Vec2i {
+    type Output = Vec2i;
+
+    fn mul(self, amt: i32) -> Vec2i {
+        Vec2i(self.0 * amt, self.1 * amt)
+    }
+}
+
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct Vec2f(pub f64, pub f64);
+
+impl Vec2f {
+    // counterclockwise in degrees. whole quarter turns are done exactly so
+    // that the puzzle's own instructions never pick up rounding errors
+    pub fn rotate_left(self, degrees: f64) -> Vec2f {
+        let quarter_turns = (degrees / 90.0).floor();
+        let rest = degrees - quarter_turns * 90.0;
+        let Vec2f(n, e) = self;
+        let Vec2f(n, e) = match (quarter_turns as i64).rem_euclid(4) {
+            0 => Vec2f(n, e),
+            1 => Vec2f(e, -n),
+            2 => Vec2f(-n, -e),
+            _ => Vec2f(-e, n),
+        };
+
+        if rest == 0.0 {
+            Vec2f(n, e)
+        } else {
+            let (sin, cos) = rest.to_radians().sin_cos();
+            Vec2f(e * sin + n * cos, e * cos - n * sin)
+        }
+    }
+
+    pub fn manhattan(self) -> f64 {
+        self.0.abs() + self.1.abs()
+    }
+
+    pub fn euclidean(self) -> f64 {
+        self.0.hypot(self.1)
+    }
+
+    pub fn round(self) -> Vec2i {
+        Vec2i(self.0.round() as i32, self.1.round() as i32)
+    }
+}
+
+impl From<Vec2i> for Vec2f {
+    fn from(Vec2i(n, e): Vec2i) -> Vec2f {
+        Vec2f(f64::from(n), f64::from(e))
+    }
+}
+
+impl Add for Vec2f {
+    type Output = Vec2f;
+
+    fn add(self, other: Vec2f) -> Vec2f {
+        Vec2f(self.0 + other.0, self.1 + other.1)
+    }
+}
+
+impl AddAssign for Vec2f {
+    fn add_assign(&mut self, other: Vec2f) {
+        *self = *self + other;
+    }
+}
+
+impl Neg for Vec2f {
+    type Output = Vec2f;
+
+    fn neg(self) -> Vec2f {
+        Vec2f(-self.0, -self.1)
+    }
+}
+
+impl Mul<f64> for Vec2f {
+    type Output = Vec2f;
+
+    fn mul(self, amt: f64) -> Vec2f {
+        Vec2f(self.0 * amt, self.1 * amt)
+    }
+}
+
+impl Direction {
+    fn unit(self) -> Vec2i {
+        match self {
+            N => Vec2i(1, 0),
+            S => Vec2i(-1, 0),
+            E => Vec2i(0, 1),
+            W => Vec2i(0, -1),
+        }
+    }
+}
+
+impl Rotation {
+    fn degrees(self, amt: i32) -> f64 {
+        match self {
+            L => f64::from(amt),
+            R => -f64::from(amt),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Model {
+    Ship,
+    Waypoint,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct State {
+    pub ship: Vec2f,
+    pub heading: Vec2f,
+    pub waypoint: Vec2f,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        State {
+            ship: Vec2f(0.0, 0.0),
+            heading: E.unit().into(),
+            waypoint: Vec2f(1.0, 10.0),
+        }
+    }
+}
+
+impl State {
+    fn step(self, model: Model, (i, amt): (Instruction, i32)) -> State {
+        let State {
+            mut ship,
+            mut heading,
+            mut waypoint,
+        } = self;
+
+        let amt_f = f64::from(amt);
+        match (model, i) {
+            (_, Instruction::Reset) => {
+                heading = State::default().heading;
+                waypoint = State::default().waypoint;
+            }
+            (Model::Ship, Instruction::Rotation(r)) => {
+                heading = heading.rotate_left(r.degrees(amt))
+            }
+            (Model::Ship, Instruction::Direction(d)) => ship += Vec2f::from(d.unit()) * amt_f,
+            (Model::Ship, Instruction::F) => ship += heading * amt_f,
+            (Model::Ship, Instruction::B) => ship += -heading * amt_f,
+            (Model::Ship, Instruction::Reverse) => heading = -heading,
+            // the ship turns along with its waypoint
+            (Model::Waypoint, Instruction::Rotation(r)) => {
+                heading = heading.rotate_left(r.degrees(amt));
+                waypoint = waypoint.rotate_left(r.degrees(amt));
+            }
+            (Model::Waypoint, Instruction::Direction(d)) => {
+                waypoint += Vec2f::from(d.unit()) * amt_f
+            }
+            (Model::Waypoint, Instruction::F) => ship += waypoint * amt_f,
+            (Model::Waypoint, Instruction::B) => ship += -waypoint * amt_f,
+            (Model::Waypoint, Instruction::Reverse) => {
+                heading = -heading;
+                waypoint = -waypoint;
+            }
+        }
+
+        State {
+            ship,
+            heading,
+            waypoint,
+        }
+    }
+}
+
+pub struct Trace<'a> {
+    model: Model,
+    state: State,
+    directions: std::slice::Iter<'a, (Instruction, i32)>,
+}
+
+impl Iterator for Trace<'_> {
+    type Item = State;
+
+    fn next(&mut self) -> Option<State> {
+        let instruction = self.directions.next()?;
+        self.state = self.state.step(self.model, *instruction);
+        Some(self.state)
+    }
+}
+
+pub fn trace(model: Model, directions: &[(Instruction, i32)]) -> Trace<'_> {
+    Trace {
+        model,
+        state: State::default(),
+        directions: directions.iter(),
+    }
+}
+
+// the ship position at the start and after every instruction
+pub fn track(model: Model, directions: &[(Instruction, i32)]) -> Vec<Vec2f> {
+    std::iter::once(State::default().ship)
+        .chain(trace(model, directions).map(|x| x.ship))
+        .collect()
+}
+
+fn follow(model: Model, directions: &[(Instruction, i32)]) -> Vec2f {
+    trace(model, directions).last().unwrap_or_default().ship
+}
+
+fn follow_1(directions: &[(Instruction, i32)]) -> Vec2i {
+    follow(Model::Ship, directions).round()
+}
+
+fn follow_2(directions: &[(Instruction, i32)]) -> Vec2i {
+    follow(Model::Waypoint, directions).round()
+}
+
+fn solve_part1(input: &[(Instruction, i32)]) -> i32 {
+    follow_1(input).manhattan()
+}
+
+fn solve_part2(input: &[(Instruction, i32)]) -> i32 {
+    follow_2(input).manhattan()
+}
+
+pub struct Day12;
+
+impl Solver for Day12 {
+    type Input<'a> = Vec<(Instruction, i32)>;
+
+    fn parse(&self, input: &str) -> Result<Vec<(Instruction, i32)>, Box<dyn Error>> {
+        Ok(input_generator(input)?)
+    }
+
+    fn part1(&self, input: &Vec<(Instruction, i32)>) -> Answer {
+        Ok(Box::new(solve_part1(input)))
+    }
+
+    fn part2(&self, input: &Vec<(Instruction, i32)>) -> Answer {
+        Ok(Box::new(solve_part2(input)))
+    }
+}
+
+aoc_solver!(day12, Day12);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn part1() {
+        assert_eq!(
+            Vec2i(-8, 17),
+            follow_1(&[
+                (Instruction::F, 10),
+                (Instruction::Direction(N), 3),
+                (Instruction::F, 7),
+                (Instruction::Rotation(R), 90),
+                (Instruction::F, 11)
+            ])
+        );
+    }
+    #[test]
+    fn part2() {
+        assert_eq!(
+            Vec2i(-72, 214),
+            follow_2(&[
+                (Instruction::F, 10),
+                (Instruction::Direction(N), 3),
+                (Instruction::F, 7),
+                (Instruction::Rotation(R), 90),
+                (Instruction::F, 11)
+            ])
+        );
+    }
+    #[test]
+    fn rotate() {
+        assert_eq!(Vec2i(4, 10).rotate_left(1), Vec2i(10, -4));
+        assert_eq!(Vec2i(4, 10).rotate_left(-1), Vec2i(-10, 4));
+        assert_eq!(Vec2i(4, 10).rotate_left(3), Vec2i(4, 10).rotate_left(-1));
+        assert_eq!(Vec2i(4, 10).rotate_left(-2), Vec2i(-4, -10));
+        assert_eq!(
+            Vec2f(4.0, 10.0).rotate_left(L.degrees(90)),
+            Vec2f(10.0, -4.0)
+        );
+        assert_eq!(
+            Vec2f(4.0, 10.0).rotate_left(R.degrees(450)),
+            Vec2f(-10.0, 4.0)
+        );
+    }
+    #[test]
+    fn part2_left_turns() {
+        assert_eq!(
+            Vec2i(9, -1),
+            follow_2(&[
+                (Instruction::Rotation(L), 90),
+                (Instruction::Direction(S), 1),
+                (Instruction::F, 1),
+            ])
+        );
+    }
+
+    #[test]
+    fn trace_example() {
+        let directions = input_generator("F10\nN3\nF7\nR90\nF11").unwrap();
+        let states: Vec<_> = trace(Model::Waypoint, &directions).collect();
+
+        assert_eq!(states.len(), 5);
+        assert_eq!(
+            states[3],
+            State {
+                ship: Vec2f(38.0, 170.0),
+                heading: S.unit().into(),
+                waypoint: Vec2f(-10.0, 4.0),
+            }
+        );
+        assert_eq!(
+            track(Model::Ship, &directions),
+            vec![
+                Vec2f(0.0, 0.0),
+                Vec2f(0.0, 10.0),
+                Vec2f(3.0, 10.0),
+                Vec2f(3.0, 17.0),
+                Vec2f(3.0, 17.0),
+                Vec2f(-8.0, 17.0),
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(
+            input_generator("F10\nN3\n\nX7"),
+            Err(ParseError::UnknownInstruction {
+                line: 4,
+                instruction: 'X',
+            })
+        );
+        assert_eq!(
+            input_generator("F10\nR9o"),
+            Err(ParseError::BadAmount {
+                line: 2,
+                amount: "9o".to_owned(),
+            })
+        );
+        assert_eq!(
+            input_generator("U\nZ0\nB3"),
+            Ok(vec![
+                (Instruction::Reverse, 0),
+                (Instruction::Reset, 0),
+                (Instruction::B, 3)
+            ])
+        );
+    }
+    #[test]
+    fn arbitrary_angles() {
+        let directions = input_generator("L45\nF10\nR45\nF10").unwrap();
+        let ship = follow(Model::Ship, &directions);
+
+        assert!((ship.0 - 50f64.sqrt()).abs() < 1e-9);
+        assert!((ship.1 - (50f64.sqrt() + 10.0)).abs() < 1e-9);
+        assert_eq!(follow_1(&directions), Vec2i(7, 17));
+
+        let directions = input_generator("R30\nF2").unwrap();
+        let ship = follow(Model::Waypoint, &directions);
+        let expected = Vec2f(1.0, 10.0).rotate_left(-30.0) * 2.0;
+        assert!((ship.0 - expected.0).abs() < 1e-9 && (ship.1 - expected.1).abs() < 1e-9);
+    }
+    #[test]
+    fn extended_instructions() {
+        let directions = input_generator("F10\nU\nB4\nN3\nL90\nZ\nF1").unwrap();
+
+        assert_eq!(follow_1(&directions), Vec2i(3, 15));
+        // waypoint goes (1, 10) -> (-1, -10) -> (2, -10) -> (-10, -2) -> (1, 10)
+        assert_eq!(follow_2(&directions), Vec2i(15, 150));
+    }
+
+    proptest! {
+        #[test]
+        fn four_left_turns_are_identity(n in -10000..10000, e in -10000..10000) {
+            let v = Vec2i(n, e);
+            prop_assert_eq!((0..4).fold(v, |v, _| v.rotate_left(1)), v);
+            prop_assert_eq!(v.rotate_left(4), v);
+            let f = Vec2f::from(v);
+            prop_assert_eq!((0..4).fold(f, |f, _| f.rotate_left(L.degrees(90))), f);
+        }
+
+        #[test]
+        fn left_undoes_right(n in -10000..10000, e in -10000..10000, turns in -8..8) {
+            let v = Vec2i(n, e);
+            prop_assert_eq!(v.rotate_left(turns).rotate_left(-turns), v);
+            prop_assert_eq!(v.rotate_left(1).rotate_left(-1), v);
+        }
+
+        #[test]
+        fn rotation_preserves_norms(n in -10000..10000, e in -10000..10000, turns in -8..8) {
+            let v = Vec2i(n, e);
+            prop_assert_eq!(v.rotate_left(turns).manhattan(), v.manhattan());
+            prop_assert_eq!(v.rotate_left(turns).euclidean(), v.euclidean());
+        }
+
+        #[test]
+        fn arbitrary_rotation_round_trips(n in -1000.0..1000.0, e in -1000.0..1000.0, degrees in -720.0..720.0) {
+            let v = Vec2f(n, e);
+            let back = v.rotate_left(degrees).rotate_left(-degrees);
+            prop_assert!((back.0 - n).abs() < 1e-6 && (back.1 - e).abs() < 1e-6);
+            prop_assert!((v.rotate_left(degrees).euclidean() - v.euclidean()).abs() < 1e-6);
+        }
+    }
+}
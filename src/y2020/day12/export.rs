@@ -0,0 +1,91 @@
+use super::Vec2f;
+use serde_json::json;
+use std::fmt::Write;
+
+// adding zero turns -0 into 0 so it doesn't show up in the output
+fn coord(x: f64) -> f64 {
+    x + 0.0
+}
+
+// svg's y axis points south, so north is flipped
+pub fn to_svg(track: &[Vec2f]) -> String {
+    // the origin is always kept in view
+    let min_e = track.iter().map(|x| x.1).fold(0.0, f64::min);
+    let max_e = track.iter().map(|x| x.1).fold(0.0, f64::max);
+    let min_n = track.iter().map(|x| x.0).fold(0.0, f64::min);
+    let max_n = track.iter().map(|x| x.0).fold(0.0, f64::max);
+    let margin = 1.0 + ((max_e - min_e).max(max_n - min_n) / 20.0).floor();
+
+    let mut points = String::new();
+    for (idx, Vec2f(n, e)) in track.iter().enumerate() {
+        if idx > 0 {
+            points.push(' ');
+        }
+        write!(points, "{},{}", coord(*e), coord(-n)).unwrap();
+    }
+
+    format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"{} {} {} {}\">
+  <polyline points=\"{}\" fill=\"none\" stroke=\"black\" vector-effect=\"non-scaling-stroke\"/>
+</svg>
+",
+        coord(min_e - margin),
+        coord(-max_n - margin),
+        max_e - min_e + 2.0 * margin,
+        max_n - min_n + 2.0 * margin,
+        points
+    )
+}
+
+// coordinates are [east, north], matching geojson's [x, y] ordering
+pub fn to_geojson(track: &[Vec2f]) -> String {
+    let coordinates: Vec<_> = track
+        .iter()
+        .map(|Vec2f(n, e)| json!([coord(*e), coord(*n)]))
+        .collect();
+
+    serde_json::to_string_pretty(&json!({
+        "type": "Feature",
+        "properties": {},
+        "geometry": {
+            "type": "LineString",
+            "coordinates": coordinates,
+        },
+    }))
+    .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{input_generator, track, Model};
+    use super::*;
+    use serde_json::Value;
+
+    #[test]
+    fn svg() {
+        let track = track(
+            Model::Ship,
+            &input_generator("F10\nN3\nF7\nR90\nF11").unwrap(),
+        );
+
+        assert_eq!(
+            to_svg(&track),
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"-1 -4 19 13\">
+  <polyline points=\"0,0 10,0 10,-3 17,-3 17,-3 17,8\" fill=\"none\" stroke=\"black\" vector-effect=\"non-scaling-stroke\"/>
+</svg>
+"
+        );
+    }
+
+    #[test]
+    fn geojson() {
+        let track = track(Model::Waypoint, &input_generator("F10\nN3").unwrap());
+        let value: Value = serde_json::from_str(&to_geojson(&track)).unwrap();
+
+        assert_eq!(value["geometry"]["type"], "LineString");
+        assert_eq!(
+            value["geometry"]["coordinates"],
+            json!([[0.0, 0.0], [100.0, 10.0], [100.0, 10.0]])
+        );
+    }
+}
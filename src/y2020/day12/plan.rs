@@ -0,0 +1,311 @@
+use super::{follow, Direction, Instruction, Model, Rotation, State, Vec2f, Vec2i};
+use Direction::*;
+
+const MAX: i64 = i32::MAX as i64;
+
+// amt as the fewest instructions that each fit in an i32
+fn split(instruction: Instruction, amt: i64) -> Vec<(Instruction, i32)> {
+    let mut out = vec![];
+    let mut left = amt;
+    while left > 0 {
+        let step = left.min(MAX);
+        out.push((instruction, step as i32));
+        left -= step;
+    }
+    out
+}
+
+fn towards(positive: Direction, negative: Direction, amt: i64) -> Vec<(Instruction, i32)> {
+    if amt >= 0 {
+        split(Instruction::Direction(positive), amt)
+    } else {
+        split(Instruction::Direction(negative), -amt)
+    }
+}
+
+fn turn(quarter_turns: i32) -> Option<(Instruction, i32)> {
+    match quarter_turns {
+        0 => None,
+        3 => Some((Instruction::Rotation(Rotation::R), 90)),
+        turns => Some((Instruction::Rotation(Rotation::L), 90 * turns)),
+    }
+}
+
+fn divisors(n: i64) -> Vec<i64> {
+    let mut small = vec![];
+    let mut large = vec![];
+    let mut d = 1;
+    while d * d <= n {
+        if n % d == 0 {
+            small.push(d);
+            if d * d != n {
+                large.push(n / d);
+            }
+        }
+        d += 1;
+    }
+    small.extend(large.into_iter().rev());
+    small
+}
+
+fn gcd(a: i64, b: i64) -> i64 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+// every instruction moves the ship along one axis by at most i32::MAX, so
+// each axis needs its own instructions and N/S/E/W alone are as short as
+// anything
+fn ship(Vec2i(n, e): Vec2i) -> Vec<Vec<(Instruction, i32)>> {
+    let mut plan = towards(N, S, n.into());
+    plan.extend(towards(E, W, e.into()));
+    vec![plan]
+}
+
+// the waypoint model only moves the ship with F. a plan that does all its
+// waypoint changes before its Fs reaches k * w, where w is the waypoint and k
+// the total of the Fs. moves commute with turns (as turned moves), so the
+// fewest changes to reach w are one optional turn then the moves per axis.
+// with k = 1 and the turn that points the waypoint into the target's
+// quadrant that is never more than four instructions, so the only other
+// plans that could be shorter are F, one waypoint change, F
+fn waypoint(target: Vec2i) -> Vec<Vec<(Instruction, i32)>> {
+    let way = State::default().waypoint.round();
+    let (n, e) = (i64::from(target.0), i64::from(target.1));
+    let mut out = vec![];
+
+    if n == 0 && e == 0 {
+        return vec![vec![]];
+    }
+
+    for k in divisors(gcd(n.abs(), e.abs())) {
+        for turns in 0..4 {
+            let Vec2i(rn, re) = way.rotate_left(turns);
+            let mut plan: Vec<_> = turn(turns).into_iter().collect();
+            plan.extend(towards(N, S, n / k - i64::from(rn)));
+            plan.extend(towards(E, W, e / k - i64::from(re)));
+            plan.extend(split(Instruction::F, k));
+            out.push(plan);
+        }
+    }
+
+    let (wn, we) = (i64::from(way.0), i64::from(way.1));
+    let fits = |x: i64| (1..=MAX).contains(&x);
+
+    // F a, turn, F b: a * way + b * turned = target
+    for turns in 1..4 {
+        let Vec2i(rn, re) = way.rotate_left(turns);
+        let (rn, re) = (i64::from(rn), i64::from(re));
+        let det = wn * re - we * rn;
+        if det == 0 {
+            continue;
+        }
+        let (a, b) = (n * re - e * rn, wn * e - we * n);
+        if a % det == 0 && b % det == 0 && fits(a / det) && fits(b / det) {
+            out.push(vec![
+                (Instruction::F, (a / det) as i32),
+                turn(turns).unwrap(),
+                (Instruction::F, (b / det) as i32),
+            ]);
+        }
+    }
+
+    // F a, move the waypoint by m along one axis, F b: (a + b) * way plus b * m
+    // along that axis. the other axis fixes a + b, which leaves the
+    // divisors of the rest for b
+    let axes = [((n, e), (wn, we), (N, S)), ((e, n), (we, wn), (E, W))];
+    for &((along, across), (way_along, way_across), (positive, negative)) in axes.iter() {
+        if way_across == 0 || across % way_across != 0 {
+            continue;
+        }
+        let total = across / way_across;
+        let rest = along - total * way_along;
+        if rest == 0 {
+            continue;
+        }
+        for b in divisors(rest.abs()) {
+            let (a, m) = (total - b, rest / b);
+            if fits(a) && fits(b) && fits(m.abs()) {
+                let mut plan = vec![(Instruction::F, a as i32)];
+                plan.extend(towards(positive, negative, m));
+                plan.push((Instruction::F, b as i32));
+                out.push(plan);
+            }
+        }
+    }
+
+    out
+}
+
+// a shortest list of the puzzle's own instructions (NSEWLRF, turning in
+// multiples of 90) that takes the ship from the origin to the target. every
+// target is reachable under both models
+pub fn plan(model: Model, target: Vec2i) -> Vec<(Instruction, i32)> {
+    let candidates = match model {
+        Model::Ship => ship(target),
+        Model::Waypoint => waypoint(target),
+    };
+
+    candidates
+        .into_iter()
+        .filter(|x| follow(model, x) == Vec2f::from(target))
+        .min_by_key(|x| x.len())
+        .unwrap()
+}
+
+// back into the puzzle's text format
+pub fn to_input(directions: &[(Instruction, i32)]) -> String {
+    directions
+        .iter()
+        .map(|(i, amt)| {
+            let letter = match i {
+                Instruction::Direction(N) => 'N',
+                Instruction::Direction(S) => 'S',
+                Instruction::Direction(E) => 'E',
+                Instruction::Direction(W) => 'W',
+                Instruction::Rotation(Rotation::L) => 'L',
+                Instruction::Rotation(Rotation::R) => 'R',
+                Instruction::F => 'F',
+                Instruction::B => 'B',
+                Instruction::Reverse => 'U',
+                Instruction::Reset => 'Z',
+            };
+            format!("{}{}\n", letter, amt)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::input_generator;
+    use super::*;
+    use std::collections::HashMap;
+
+    // every position reachable with at most two instructions with small amounts
+    fn brute_force(model: Model) -> HashMap<Vec2i, usize> {
+        let mut options = vec![];
+        for amt in 1..=30 {
+            for d in [N, S, E, W].iter() {
+                options.push((Instruction::Direction(*d), amt));
+            }
+            options.push((Instruction::F, amt));
+        }
+        for amt in [90, 180, 270].iter() {
+            options.push((Instruction::Rotation(Rotation::L), *amt));
+            options.push((Instruction::Rotation(Rotation::R), *amt));
+        }
+
+        let mut best = HashMap::new();
+        best.insert(Vec2i(0, 0), 0);
+        for a in &options {
+            best.entry(follow(model, &[*a]).round()).or_insert(1);
+        }
+        for a in &options {
+            for b in &options {
+                best.entry(follow(model, &[*a, *b]).round()).or_insert(2);
+            }
+        }
+        best
+    }
+
+    fn check(model: Model) {
+        let best = brute_force(model);
+
+        for n in -20..=20 {
+            for e in -20..=20 {
+                let target = Vec2i(n, e);
+                let plan = plan(model, target);
+
+                assert_eq!(follow(model, &plan), Vec2f::from(target));
+                assert_eq!(plan.len(), *best.get(&target).unwrap_or(&3), "{:?}", target);
+            }
+        }
+    }
+
+    #[test]
+    fn ship() {
+        check(Model::Ship);
+        assert_eq!(
+            plan(Model::Ship, Vec2i(-8, 17)),
+            vec![
+                (Instruction::Direction(S), 8),
+                (Instruction::Direction(E), 17)
+            ]
+        );
+    }
+
+    #[test]
+    fn waypoint() {
+        check(Model::Waypoint);
+        assert_eq!(
+            plan(Model::Waypoint, Vec2i(3, 30)),
+            vec![(Instruction::F, 3)]
+        );
+        assert_eq!(
+            plan(Model::Waypoint, Vec2i(-72, 214)),
+            vec![
+                (Instruction::Direction(S), 73),
+                (Instruction::Direction(E), 204),
+                (Instruction::F, 1)
+            ]
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        let plan = plan(Model::Waypoint, Vec2i(-10, 1));
+
+        assert_eq!(to_input(&plan), "R90\nF1\n");
+        assert_eq!(input_generator(&to_input(&plan)), Ok(plan));
+    }
+
+    #[test]
+    fn large_targets() {
+        for model in [Model::Ship, Model::Waypoint].iter() {
+            for target in [
+                Vec2i(i32::MAX, i32::MAX),
+                Vec2i(i32::MIN, i32::MIN),
+                Vec2i(i32::MIN + 20, i32::MAX),
+                Vec2i(i32::MAX, i32::MIN + 20),
+                Vec2i(i32::MIN, 1),
+            ]
+            .iter()
+            {
+                let plan = plan(*model, *target);
+                assert_eq!(follow(*model, &plan), Vec2f::from(*target));
+            }
+        }
+
+        // south by 2^31 doesn't fit in one instruction
+        assert_eq!(
+            plan(Model::Ship, Vec2i(i32::MIN, 0)),
+            vec![
+                (Instruction::Direction(S), i32::MAX),
+                (Instruction::Direction(S), 1)
+            ]
+        );
+
+        // e.g. S3, W10, F1073741824
+        let found = plan(Model::Waypoint, Vec2i(i32::MIN, 0));
+        assert_eq!(found.len(), 3);
+        assert_eq!(
+            follow(Model::Waypoint, &found),
+            Vec2f::from(Vec2i(i32::MIN, 0))
+        );
+
+        // only reachable in three with two separate Fs
+        let target = Vec2i(i32::MIN, 214748385);
+        assert_eq!(
+            plan(Model::Waypoint, target),
+            vec![
+                (Instruction::F, 2),
+                (Instruction::Rotation(Rotation::R), 90),
+                (Instruction::F, 214748365)
+            ]
+        );
+        assert_eq!(plan(Model::Waypoint, Vec2i(i32::MIN, 3)).len(), 4);
+    }
+}
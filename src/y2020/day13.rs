@@ -0,0 +1,414 @@
+use crate::solver::{aoc_solver, Answer, Solver};
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+use std::error::Error;
+use std::fmt;
+
+#[derive(Debug, PartialEq)]
+pub struct Notes {
+    departure_time: Option<usize>,
+    busses: Vec<Option<usize>>,
+}
+
+// positions are indices into the bus list, which are also the part 2 offsets
+#[derive(Debug, PartialEq)]
+pub enum NotesError {
+    Malformed {
+        line: usize,
+        column: usize,
+        token: String,
+    },
+    ZeroBus {
+        position: usize,
+    },
+    DuplicateBus {
+        bus: usize,
+        first: usize,
+        second: usize,
+    },
+    TooManyLines,
+    NoBusses,
+    MissingDepartureTime,
+    NoContestTime,
+}
+
+impl fmt::Display for NotesError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            NotesError::Malformed {
+                line,
+                column,
+                token,
+            } => write!(f, "line {}, column {}: malformed {:?}", line, column, token),
+            NotesError::ZeroBus { position } => write!(f, "bus at position {} is 0", position),
+            NotesError::DuplicateBus { bus, first, second } => write!(
+                f,
+                "bus {} appears at positions {} and {}",
+                bus, first, second
+            ),
+            NotesError::TooManyLines => write!(f, "expected at most two lines"),
+            NotesError::NoBusses => write!(f, "no busses in service"),
+            NotesError::MissingDepartureTime => write!(f, "no departure time"),
+            NotesError::NoContestTime => write!(f, "no contest time"),
+        }
+    }
+}
+
+impl Error for NotesError {}
+
+impl Notes {
+    pub fn new(
+        departure_time: Option<usize>,
+        busses: Vec<Option<usize>>,
+    ) -> Result<Notes, NotesError> {
+        let mut seen = HashMap::new();
+        for (position, bus) in busses.iter().enumerate() {
+            match bus {
+                None => {}
+                Some(0) => return Err(NotesError::ZeroBus { position }),
+                Some(bus) => {
+                    if let Some(first) = seen.insert(*bus, position) {
+                        return Err(NotesError::DuplicateBus {
+                            bus: *bus,
+                            first,
+                            second: position,
+                        });
+                    }
+                }
+            }
+        }
+        if seen.is_empty() {
+            return Err(NotesError::NoBusses);
+        }
+
+        Ok(Notes {
+            departure_time,
+            busses,
+        })
+    }
+
+    // every departure of every bus at or after t, in time order
+    pub fn departures(&self, t: usize) -> Departures {
+        Departures(
+            self.busses
+                .iter()
+                .filter_map(|x| *x)
+                .map(|bus| Reverse((t + (bus - t % bus) % bus, bus)))
+                .collect(),
+        )
+    }
+
+    // (bus, offset) pairs for the part 2 contest
+    pub fn pattern(&self) -> Vec<(usize, usize)> {
+        self.busses
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, bus)| bus.map(|bus| (bus, idx)))
+            .collect()
+    }
+
+    pub fn next_contest_time(&self) -> Option<usize> {
+        next_pattern(&self.pattern(), self.departure_time.unwrap_or(0))
+    }
+}
+
+pub struct Departures(BinaryHeap<Reverse<(usize, usize)>>);
+
+impl Iterator for Departures {
+    type Item = (usize, usize);
+
+    fn next(&mut self) -> Option<(usize, usize)> {
+        let Reverse((time, bus)) = self.0.pop()?;
+        self.0.push(Reverse((time + bus, bus)));
+        Some((time, bus))
+    }
+}
+
+// the earliest t after `after` where every bus departs at t + offset.
+// each bus is lined up in turn while stepping by the period of the ones
+// already in place, so it also copes with ids that aren't coprime. a bus 0
+// never departs, so a pattern with one has no time
+pub fn next_pattern(pattern: &[(usize, usize)], after: usize) -> Option<usize> {
+    let mut t = after.checked_add(1)?;
+    let mut step = 1;
+
+    for (bus, offset) in pattern {
+        if *bus == 0 {
+            return None;
+        }
+        let mut tries = 0;
+        while (t.checked_add(*offset)?) % bus != 0 {
+            t = t.checked_add(step)?;
+            tries += 1;
+            if tries == *bus {
+                return None;
+            }
+        }
+        step = step / gcd(step, *bus) * bus;
+    }
+
+    Some(t)
+}
+
+fn gcd(a: usize, b: usize) -> usize {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+fn malformed(line: usize, column: usize, token: &str) -> NotesError {
+    NotesError::Malformed {
+        line,
+        column,
+        token: token.to_owned(),
+    }
+}
+
+// the departure time line is optional, a single line is just the bus list
+pub fn input_generator(input: &str) -> Result<Notes, NotesError> {
+    let lines: Vec<_> = input
+        .lines()
+        .enumerate()
+        .filter(|(_, x)| !x.trim().is_empty())
+        .collect();
+
+    let (departure_time, (bus_line, busses)) = match lines.as_slice() {
+        [] => return Err(NotesError::NoBusses),
+        [busses] => (None, *busses),
+        [(idx, time), busses] => {
+            let token = time.trim();
+            let column = time.chars().take_while(|x| x.is_whitespace()).count() + 1;
+            let time = token
+                .parse()
+                .map_err(|_| malformed(idx + 1, column, token))?;
+            (Some(time), *busses)
+        }
+        _ => return Err(NotesError::TooManyLines),
+    };
+
+    let mut column = 1;
+    let mut parsed = vec![];
+    for raw in busses.split(',') {
+        let token = raw.trim();
+        let start = column + raw.chars().take_while(|x| x.is_whitespace()).count();
+        parsed.push(match token {
+            "x" => None,
+            _ => Some(
+                token
+                    .parse()
+                    .map_err(|_| malformed(bus_line + 1, start, token))?,
+            ),
+        });
+        column += raw.chars().count() + 1;
+    }
+
+    Notes::new(departure_time, parsed)
+}
+
+fn solve_part1(input: &Notes) -> Result<usize, NotesError> {
+    let departure_time = input
+        .departure_time
+        .ok_or(NotesError::MissingDepartureTime)?;
+    let waiting_times = input
+        .busses
+        .iter()
+        .filter_map(|x| *x)
+        .map(|bus| (bus - departure_time % bus, bus));
+    let (min_waiting_time, best_bus) = waiting_times.min().ok_or(NotesError::NoBusses)?;
+    Ok(min_waiting_time * best_bus)
+}
+
+fn solve_part2(input: &Notes) -> Result<usize, NotesError> {
+    next_pattern(&input.pattern(), 0).ok_or(NotesError::NoContestTime)
+}
+
+pub struct Day13;
+
+impl Solver for Day13 {
+    type Input<'a> = Notes;
+
+    fn parse(&self, input: &str) -> Result<Notes, Box<dyn Error>> {
+        Ok(input_generator(input)?)
+    }
+
+    fn part1(&self, input: &Notes) -> Answer {
+        Ok(Box::new(solve_part1(input)?))
+    }
+
+    fn part2(&self, input: &Notes) -> Answer {
+        Ok(Box::new(solve_part2(input)?))
+    }
+}
+
+aoc_solver!(day13, Day13);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn part1() {
+        let notes = input_generator("939\n7,13,x,x,59,x,31,19\n").unwrap();
+        assert_eq!(solve_part1(&notes), Ok(295));
+    }
+    #[test]
+    fn part2() {
+        let inputs = [
+            (vec![Some(17), None, Some(13), Some(19)], 3417),
+            (vec![Some(67), Some(7), Some(59), Some(61)], 754018),
+            (vec![Some(67), None, Some(7), Some(59), Some(61)], 779210),
+            (vec![Some(67), Some(7), None, Some(59), Some(61)], 1261476),
+            (vec![Some(1789), Some(37), Some(47), Some(1889)], 1202161486),
+        ];
+
+        for (input, output) in inputs.iter() {
+            assert_eq!(
+                Ok(*output),
+                solve_part2(&Notes::new(None, input.clone()).unwrap())
+            )
+        }
+    }
+    #[test]
+    fn part2_leading_gaps() {
+        let notes = input_generator("x,x,x,7,13").unwrap();
+        assert_eq!(solve_part2(&notes), Ok(74));
+    }
+    #[test]
+    fn part2_offset_past_bus() {
+        let notes = input_generator("939\nx,x,x,x,x,x,3").unwrap();
+        assert_eq!(solve_part2(&notes), Ok(3));
+
+        let notes = input_generator("5,x,x,x,x,x,x,x,3").unwrap();
+        assert_eq!(solve_part2(&notes), Ok(10));
+        assert_eq!(solve_part2(&input_generator("6,x,4").unwrap()), Ok(6));
+        assert_eq!(
+            solve_part2(&input_generator("6,4").unwrap()),
+            Err(NotesError::NoContestTime)
+        );
+    }
+    #[test]
+    fn departures() {
+        let notes = Notes {
+            departure_time: Some(939),
+            busses: vec![Some(7), Some(13), None, None, Some(59), None, Some(31)],
+        };
+
+        assert_eq!(
+            notes.departures(939).take(5).collect::<Vec<_>>(),
+            vec![(944, 59), (945, 7), (949, 13), (952, 7), (959, 7)]
+        );
+        assert_eq!(notes.departures(945).next(), Some((945, 7)));
+    }
+    #[test]
+    fn patterns() {
+        let notes = Notes {
+            departure_time: Some(1068782),
+            busses: vec![
+                Some(7),
+                Some(13),
+                None,
+                None,
+                Some(59),
+                None,
+                Some(31),
+                Some(19),
+            ],
+        };
+
+        assert_eq!(
+            notes.next_contest_time(),
+            Some(1068781 + 7 * 13 * 59 * 31 * 19)
+        );
+        assert_eq!(next_pattern(&notes.pattern(), 0), Some(1068781));
+        assert_eq!(
+            next_pattern(&notes.pattern(), 1068781),
+            Some(1068781 + 7 * 13 * 59 * 31 * 19)
+        );
+        assert_eq!(next_pattern(&[(6, 0), (4, 2)], 0), Some(6));
+        assert_eq!(next_pattern(&[(6, 0), (4, 1)], 0), None);
+        assert_eq!(next_pattern(&[(0, 0)], 0), None);
+        assert_eq!(next_pattern(&[(3, 0), (0, 1)], 0), None);
+    }
+    #[test]
+    fn parse() {
+        assert_eq!(
+            input_generator("17,x,13,19"),
+            Notes::new(None, vec![Some(17), None, Some(13), Some(19)])
+        );
+        assert_eq!(
+            input_generator("\n  939 \n 7, x ,13\n\n"),
+            Notes::new(Some(939), vec![Some(7), None, Some(13)])
+        );
+        assert_eq!(
+            solve_part1(&input_generator("7,13").unwrap()),
+            Err(NotesError::MissingDepartureTime)
+        );
+    }
+    #[test]
+    fn parse_errors() {
+        assert_eq!(input_generator("939\n7,13,y,59"), Err(malformed(2, 6, "y")));
+        assert_eq!(input_generator("939\n7,13, -4"), Err(malformed(2, 7, "-4")));
+        assert_eq!(input_generator(" 9a\n7"), Err(malformed(1, 2, "9a")));
+        assert_eq!(
+            input_generator("939\n7,x,0"),
+            Err(NotesError::ZeroBus { position: 2 })
+        );
+        assert_eq!(
+            input_generator("939\n7,13,x,7"),
+            Err(NotesError::DuplicateBus {
+                bus: 7,
+                first: 0,
+                second: 3
+            })
+        );
+        assert_eq!(input_generator("939\nx,x"), Err(NotesError::NoBusses));
+        assert_eq!(input_generator(""), Err(NotesError::NoBusses));
+        assert_eq!(input_generator("1\n2\n3"), Err(NotesError::TooManyLines));
+    }
+
+    // distinct primes with a few gaps between them, kept small enough for the
+    // brute force to finish quickly
+    fn busses() -> impl Strategy<Value = Vec<Option<usize>>> {
+        let primes = vec![2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31];
+        prop::sample::subsequence(primes, 1..=5)
+            .prop_shuffle()
+            .prop_flat_map(|ids| {
+                let gaps = prop::collection::vec(0usize..8, ids.len());
+                (Just(ids), gaps)
+            })
+            .prop_map(|(ids, gaps)| {
+                let mut busses = vec![];
+                for (id, gap) in ids.into_iter().zip(gaps) {
+                    busses.extend((0..gap).map(|_| None));
+                    busses.push(Some(id));
+                }
+                busses
+            })
+    }
+
+    // tries every time the largest bus departs at its offset, like the
+    // solver used to
+    fn brute_force(notes: &Notes) -> usize {
+        let pattern = notes.pattern();
+        let (largest, offset) = pattern.iter().cloned().max().unwrap();
+        let first = (largest - offset % largest) % largest;
+        let first = if first == 0 { largest } else { first };
+
+        (first..)
+            .step_by(largest)
+            .find(|t| pattern.iter().all(|(bus, offset)| (t + offset) % bus == 0))
+            .unwrap()
+    }
+
+    proptest! {
+        #[test]
+        fn brute_force_matches_solver(busses in busses()) {
+            let notes = Notes::new(None, busses).unwrap();
+
+            prop_assert_eq!(solve_part2(&notes), Ok(brute_force(&notes)));
+        }
+    }
+}
@@ -1,19 +1,20 @@
-use aoc_runner_derive::{aoc, aoc_generator};
+use crate::solver::{aoc_solver, Answer, Solver};
 use nom::{
     bytes::complete::{tag, take, take_while, take_while1},
     combinator::map_res,
     IResult,
 };
+use std::error::Error;
 use std::ops::RangeInclusive;
 
 #[derive(Debug, PartialEq, Eq)]
-struct Row {
+pub struct Row<'a> {
     rule: RangeInclusive<usize>,
     letter: char,
-    password: String,
+    password: &'a str,
 }
 
-impl Row {
+impl Row<'_> {
     fn is_valid_part_1(&self) -> bool {
         let num_inst = self.password.chars().filter(|c| *c == self.letter).count();
         self.rule.contains(&num_inst)
@@ -46,7 +47,7 @@ fn get_usize(input: &str) -> IResult<&str, usize> {
     map_res(take_while1(|c: char| c.is_digit(10)), str::parse)(input)
 }
 
-fn parse_row(input: &str) -> IResult<&str, Row> {
+fn parse_row(input: &str) -> IResult<&str, Row<'_>> {
     let (input, min) = get_usize(input)?;
     let (input, _) = tag("-")(input)?;
     let (input, max) = get_usize(input)?;
@@ -60,29 +61,46 @@ fn parse_row(input: &str) -> IResult<&str, Row> {
         Row {
             rule: min..=max,
             letter: letter,
-            password: password.to_owned(),
+            password,
         },
     ))
 }
 
-#[aoc_generator(day2)]
-fn input_generator(input: &str) -> Vec<Row> {
+fn input_generator(input: &str) -> Vec<Row<'_>> {
     input
         .lines()
         .map(|line| parse_row(line).unwrap().1)
         .collect()
 }
 
-#[aoc(day2, part1)]
-fn solve_part1(input: &[Row]) -> usize {
+fn solve_part1(input: &[Row<'_>]) -> usize {
     input.iter().filter(|r| r.is_valid_part_1()).count()
 }
 
-#[aoc(day2, part2)]
-fn solve_part2(input: &[Row]) -> usize {
+fn solve_part2(input: &[Row<'_>]) -> usize {
     input.iter().filter(|r| r.is_valid_part_2()).count()
 }
 
+pub struct Day2;
+
+impl Solver for Day2 {
+    type Input<'a> = Vec<Row<'a>>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Vec<Row<'a>>, Box<dyn Error>> {
+        Ok(input_generator(input))
+    }
+
+    fn part1(&self, input: &Vec<Row<'_>>) -> Answer {
+        Ok(Box::new(solve_part1(input)))
+    }
+
+    fn part2(&self, input: &Vec<Row<'_>>) -> Answer {
+        Ok(Box::new(solve_part2(input)))
+    }
+}
+
+aoc_solver!(day2, Day2, borrowed);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -98,7 +116,7 @@ mod tests {
             Row {
                 rule: 18..=19,
                 letter: 'p',
-                password: "fvpkgfkfjgwllqwhrjd".to_owned(),
+                password: "fvpkgfkfjgwllqwhrjd",
             }
         )
     }
@@ -110,17 +128,17 @@ mod tests {
                 Row {
                     rule: 1..=3,
                     letter: 'a',
-                    password: "abcde".to_owned()
+                    password: "abcde"
                 },
                 Row {
                     rule: 1..=3,
                     letter: 'b',
-                    password: "cdefg".to_owned()
+                    password: "cdefg"
                 },
                 Row {
                     rule: 2..=9,
                     letter: 'c',
-                    password: "ccccccccc".to_owned()
+                    password: "ccccccccc"
                 },
             ]),
             2
@@ -134,7 +152,7 @@ mod tests {
             Row {
                 rule: 1..=3,
                 letter: 'a',
-                password: "abcde".to_owned(),
+                password: "abcde",
             }
             .is_valid_part_2(),
         );
@@ -146,7 +164,7 @@ mod tests {
             Row {
                 rule: 1..=3,
                 letter: 'b',
-                password: "cdefg".to_owned(),
+                password: "cdefg",
             }
             .is_valid_part_2(),
         );
@@ -158,7 +176,7 @@ mod tests {
             Row {
                 rule: 2..=9,
                 letter: 'c',
-                password: "ccccccccc".to_owned(),
+                password: "ccccccccc",
             }
             .is_valid_part_2(),
         );
@@ -1,4 +1,5 @@
-use aoc_runner_derive::{aoc, aoc_generator};
+use crate::solver::{aoc_solver, Answer, Solver};
+use std::error::Error;
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 enum Square {
@@ -7,11 +8,10 @@ enum Square {
 }
 
 #[derive(Debug, PartialEq, Eq)]
-struct Line {
+pub struct Line {
     squares: Vec<Square>,
 }
 
-#[aoc_generator(day3)]
 fn input_generator(input: &str) -> Vec<Line> {
     input
         .lines()
@@ -38,12 +38,10 @@ fn check_slope(input: &[Line], rise: usize, run: usize) -> usize {
         .count()
 }
 
-#[aoc(day3, part1)]
 fn solve_part1(input: &[Line]) -> usize {
     check_slope(input, 1, 3)
 }
 
-#[aoc(day3, part2)]
 fn solve_part2(input: &[Line]) -> usize {
     check_slope(input, 1, 1)
         * check_slope(input, 1, 3)
@@ -52,45 +50,39 @@ fn solve_part2(input: &[Line]) -> usize {
         * check_slope(input, 2, 1)
 }
 
+pub struct Day3;
+
+impl Solver for Day3 {
+    type Input<'a> = Vec<Line>;
+
+    fn parse(&self, input: &str) -> Result<Vec<Line>, Box<dyn Error>> {
+        Ok(input_generator(input))
+    }
+
+    fn part1(&self, input: &Vec<Line>) -> Answer {
+        Ok(Box::new(solve_part1(input)))
+    }
+
+    fn part2(&self, input: &Vec<Line>) -> Answer {
+        Ok(Box::new(solve_part2(input)))
+    }
+}
+
+aoc_solver!(day3, Day3);
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    const EXAMPLE: &str = include_str!("../../tests/fixtures/2020/day3/example.txt");
+
     #[test]
     fn part1_example() {
-        let input = input_generator(
-            "..##.......
-#...#...#..
-.#....#..#.
-..#.#...#.#
-.#...##..#.
-..#.##.....
-.#.#.#....#
-.#........#
-#.##...#...
-#...##....#
-.#..#...#.#",
-        );
-
-        assert_eq!(solve_part1(&input), 7);
+        assert_eq!(solve_part1(&input_generator(EXAMPLE)), 7);
     }
 
     #[test]
     fn part2_example() {
-        let input = input_generator(
-            "..##.......
-#...#...#..
-.#....#..#.
-..#.#...#.#
-.#...##..#.
-..#.##.....
-.#.#.#....#
-.#........#
-#.##...#...
-#...##....#
-.#..#...#.#",
-        );
-
-        assert_eq!(solve_part2(&input), 336);
+        assert_eq!(solve_part2(&input_generator(EXAMPLE)), 336);
     }
 }
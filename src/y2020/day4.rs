@@ -1,8 +1,8 @@
-use aoc_runner_derive::{aoc, aoc_generator};
+use crate::solver::{aoc_solver, Answer, Solver};
 use std::collections::HashMap;
+use std::error::Error;
 
-#[aoc_generator(day4)]
-fn input_generator(input: &str) -> Vec<Record> {
+fn input_generator(input: &str) -> Vec<Record<'_>> {
     input
         .split_terminator("\n\n")
         .map(|x| {
@@ -10,10 +10,7 @@ fn input_generator(input: &str) -> Vec<Record> {
                 x.split_whitespace()
                     .map(|x| {
                         let mut bits = x.split(":");
-                        (
-                            bits.next().unwrap().to_owned(),
-                            bits.next().unwrap().to_owned(),
-                        )
+                        (bits.next().unwrap(), bits.next().unwrap())
                     })
                     .collect(),
             )
@@ -22,9 +19,9 @@ fn input_generator(input: &str) -> Vec<Record> {
 }
 
 #[derive(Debug)]
-struct Record(HashMap<String, String>);
+pub struct Record<'a>(HashMap<&'a str, &'a str>);
 
-impl Record {
+impl Record<'_> {
     pub fn contains_required_fields(&self) -> bool {
         self.0.contains_key("byr")
             && self.0.contains_key("iyr")
@@ -41,10 +38,10 @@ impl Record {
             let byr = self.0["byr"].parse().unwrap();
             let iyr = self.0["iyr"].parse().unwrap();
             let eyr = self.0["eyr"].parse().unwrap();
-            let hgt = &self.0["hgt"];
-            let hcl = &self.0["hcl"];
-            let ecl = &self.0["ecl"];
-            let pid = &self.0["pid"];
+            let hgt = self.0["hgt"];
+            let hcl = self.0["hcl"];
+            let ecl = self.0["ecl"];
+            let pid = self.0["pid"];
             let hgt_valid = if hgt.ends_with("cm") {
                 let hgt: usize = hgt[..hgt.len() - 2].parse().unwrap();
                 hgt >= 150 && hgt <= 193
@@ -55,7 +52,7 @@ impl Record {
                 false
             };
 
-            let ecl_valid = match &ecl[..] {
+            let ecl_valid = match ecl {
                 "amb" | "blu" | "brn" | "gry" | "grn" | "hzl" | "oth" => true,
                 _ => false,
             };
@@ -79,79 +76,60 @@ impl Record {
     }
 }
 
-#[aoc(day4, part1)]
-fn solve_part1(input: &[Record]) -> usize {
+fn solve_part1(input: &[Record<'_>]) -> usize {
     input
         .iter()
         .filter(|x| x.contains_required_fields())
         .count()
 }
 
-#[aoc(day4, part2)]
-fn solve_part2(input: &[Record]) -> usize {
+fn solve_part2(input: &[Record<'_>]) -> usize {
     input.iter().filter(|x| x.is_valid()).count()
 }
 
+pub struct Day4;
+
+impl Solver for Day4 {
+    type Input<'a> = Vec<Record<'a>>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Vec<Record<'a>>, Box<dyn Error>> {
+        Ok(input_generator(input))
+    }
+
+    fn part1(&self, input: &Vec<Record<'_>>) -> Answer {
+        Ok(Box::new(solve_part1(input)))
+    }
+
+    fn part2(&self, input: &Vec<Record<'_>>) -> Answer {
+        Ok(Box::new(solve_part2(input)))
+    }
+}
+
+aoc_solver!(day4, Day4, borrowed);
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn part1_example() {
-        let input = input_generator(
-            "ecl:gry pid:860033327 eyr:2020 hcl:#fffffd
-byr:1937 iyr:2017 cid:147 hgt:183cm
-
-iyr:2013 ecl:amb cid:350 eyr:2023 pid:028048884
-hcl:#cfa07d byr:1929
-
-hcl:#ae17e1 iyr:2013
-eyr:2024
-ecl:brn pid:760753108 byr:1931
-hgt:179cm
-
-hcl:#cfa07d eyr:2025 pid:166559648
-iyr:2011 ecl:brn hgt:59in",
-        );
+        let input = input_generator(include_str!("../../tests/fixtures/2020/day4/example.txt"));
 
         assert_eq!(solve_part1(&input), 2);
     }
 
     #[test]
     fn part2_example() {
-        assert!(input_generator(
-            "pid:087499704 hgt:74in ecl:grn iyr:2012 eyr:2030 byr:1980
-hcl:#623a2f
-
-eyr:2029 ecl:blu cid:129 byr:1989
-iyr:2014 pid:896056539 hcl:#a97842 hgt:165cm
-
-hcl:#888785
-hgt:164cm byr:2001 iyr:2015 cid:88
-pid:545766238 ecl:hzl
-eyr:2022
-
-iyr:2010 hgt:158cm hcl:#b6652a ecl:blu byr:1944 eyr:2021 pid:093154719"
-        )
-        .iter()
-        .all(|x| x.is_valid()));
-
-        assert!(input_generator(
-            "eyr:1972 cid:100
-hcl:#18171d ecl:amb hgt:170 pid:186cm iyr:2018 byr:1926
-
-iyr:2019
-hcl:#602927 eyr:1967 hgt:170cm
-ecl:grn pid:012533040 byr:1946
-
-hcl:dab227 iyr:2012
-ecl:brn hgt:182cm pid:021572410 eyr:2020 byr:1992 cid:277
+        assert!(
+            input_generator(include_str!("../../tests/fixtures/2020/day4/valid.txt"))
+                .iter()
+                .all(|x| x.is_valid())
+        );
 
-hgt:59cm ecl:zzz
-eyr:2038 hcl:74454a iyr:2023
-pid:3556412378 byr:2007"
-        )
-        .iter()
-        .all(|x| !x.is_valid()));
+        assert!(
+            input_generator(include_str!("../../tests/fixtures/2020/day4/invalid.txt"))
+                .iter()
+                .all(|x| !x.is_valid())
+        );
     }
 }
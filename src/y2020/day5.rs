@@ -1,6 +1,6 @@
-use aoc_runner_derive::{aoc, aoc_generator};
+use crate::solver::{aoc_solver, Answer, Solver};
+use std::error::Error;
 
-#[aoc_generator(day5)]
 fn input_generator(input: &str) -> String {
     input.to_owned()
 }
@@ -25,7 +25,6 @@ fn get_id(s: &[u8]) -> i32 {
     row * 8 + col
 }
 
-#[aoc(day5, part1)]
 fn solve_part1(input: &str) -> i32 {
     input
         .as_bytes()
@@ -35,7 +34,6 @@ fn solve_part1(input: &str) -> i32 {
         .unwrap()
 }
 
-#[aoc(day5, part2)]
 fn solve_part2(input: &str) -> i32 {
     let mut min = 10000;
     let mut max = 0;
@@ -50,6 +48,26 @@ fn solve_part2(input: &str) -> i32 {
     (max * (max + 1)) / 2 - (min * (min - 1)) / 2 - sum
 }
 
+pub struct Day5;
+
+impl Solver for Day5 {
+    type Input<'a> = String;
+
+    fn parse(&self, input: &str) -> Result<String, Box<dyn Error>> {
+        Ok(input_generator(input))
+    }
+
+    fn part1(&self, input: &String) -> Answer {
+        Ok(Box::new(solve_part1(input)))
+    }
+
+    fn part2(&self, input: &String) -> Answer {
+        Ok(Box::new(solve_part2(input)))
+    }
+}
+
+aoc_solver!(day5, Day5);
+
 #[cfg(test)]
 mod tests {
     use super::*;
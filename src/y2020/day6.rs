@@ -0,0 +1,104 @@
+use crate::solver::{aoc_solver, Answer, Solver};
+use std::error::Error;
+
+fn input_generator(input: &str) -> Vec<Vec<&str>> {
+    input
+        .split_terminator("\n\n")
+        .map(|x| x.lines().collect())
+        .collect()
+}
+
+fn count_yeses_to_any(input: &[&str]) -> u32 {
+    input
+        .iter()
+        .flat_map(|x| x.as_bytes().iter().map(|x| 1u32 << (x - b'a')))
+        .fold(0, |acc, x| acc | x)
+        .count_ones()
+}
+
+fn count_yeses_from_all(input: &[&str]) -> u32 {
+    input
+        .iter()
+        .map(|x| {
+            x.as_bytes()
+                .iter()
+                .map(|x| 1u32 << (x - b'a'))
+                .fold(0, |acc, x| acc | x)
+        })
+        .fold(0xffffffff, |acc, x| acc & x)
+        .count_ones()
+}
+
+fn solve_part1(input: &[Vec<&str>]) -> u32 {
+    input.iter().map(|x| count_yeses_to_any(x)).sum()
+}
+
+fn solve_part2(input: &[Vec<&str>]) -> u32 {
+    input.iter().map(|x| count_yeses_from_all(x)).sum()
+}
+
+pub struct Day6;
+
+impl Solver for Day6 {
+    type Input<'a> = Vec<Vec<&'a str>>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Vec<Vec<&'a str>>, Box<dyn Error>> {
+        Ok(input_generator(input))
+    }
+
+    fn part1(&self, input: &Vec<Vec<&str>>) -> Answer {
+        Ok(Box::new(solve_part1(input)))
+    }
+
+    fn part2(&self, input: &Vec<Vec<&str>>) -> Answer {
+        Ok(Box::new(solve_part2(input)))
+    }
+}
+
+aoc_solver!(day6, Day6, borrowed);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1_example1() {
+        assert_eq!(count_yeses_to_any(&["abc"]), 3);
+    }
+    #[test]
+    fn part1_example2() {
+        assert_eq!(count_yeses_to_any(&["a", "b", "c"]), 3);
+    }
+    #[test]
+    fn part1_example3() {
+        assert_eq!(count_yeses_to_any(&["ab", "ac"]), 3);
+    }
+    #[test]
+    fn part1_example4() {
+        assert_eq!(count_yeses_to_any(&["a", "a", "a", "a"]), 1);
+    }
+    #[test]
+    fn part1_example5() {
+        assert_eq!(count_yeses_from_all(&["b"]), 1);
+    }
+    #[test]
+    fn part2_example1() {
+        assert_eq!(count_yeses_from_all(&["abc"]), 3);
+    }
+    #[test]
+    fn part2_example2() {
+        assert_eq!(count_yeses_from_all(&["a", "b", "c"]), 0);
+    }
+    #[test]
+    fn part2_example3() {
+        assert_eq!(count_yeses_from_all(&["ab", "ac"]), 1);
+    }
+    #[test]
+    fn part2_example4() {
+        assert_eq!(count_yeses_from_all(&["a", "a", "a", "a"]), 1);
+    }
+    #[test]
+    fn part2_example5() {
+        assert_eq!(count_yeses_from_all(&["b"]), 1);
+    }
+}
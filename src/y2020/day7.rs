@@ -0,0 +1,533 @@
+use crate::count::{Count, Counter, Overflow};
+use crate::solver::{aoc_solver, Answer, Solver};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{alpha1, char, digit1, space0, space1},
+    combinator::{all_consuming, map, map_res, recognize, value, verify},
+    error::{context, ErrorKind, VerboseError, VerboseErrorKind},
+    multi::separated_list1,
+    sequence::tuple,
+    IResult,
+};
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::error::Error;
+use std::fmt;
+
+pub mod export;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Rule<'a> {
+    pub name: &'a str,
+    pub contains: Vec<(usize, &'a str)>,
+}
+
+type ParseResult<'a, T> = IResult<&'a str, T, VerboseError<&'a str>>;
+
+fn colour(input: &str) -> ParseResult<'_, &str> {
+    context(
+        "colour",
+        recognize(separated_list1(
+            space1,
+            verify(alpha1, |word: &str| {
+                !matches!(word, "bag" | "bags" | "contain")
+            }),
+        )),
+    )(input)
+}
+
+fn bag(input: &str) -> ParseResult<'_, &str> {
+    context("\"bag\" or \"bags\"", alt((tag("bags"), tag("bag"))))(input)
+}
+
+fn content(input: &str) -> ParseResult<'_, (usize, &str)> {
+    map(
+        tuple((
+            context("count", map_res(digit1, str::parse)),
+            space1,
+            colour,
+            space1,
+            bag,
+        )),
+        |(count, _, name, _, _)| (count, name),
+    )(input)
+}
+
+fn contents(input: &str) -> ParseResult<'_, Vec<(usize, &str)>> {
+    alt((
+        value(
+            vec![],
+            tuple((tag("no"), space1, tag("other"), space1, bag)),
+        ),
+        separated_list1(tuple((space0, char(','), space0)), content),
+    ))(input)
+}
+
+fn parse_rule(input: &str) -> ParseResult<'_, Rule<'_>> {
+    map(
+        tuple((
+            space0,
+            colour,
+            space1,
+            bag,
+            space1,
+            context("\"contain\"", tag("contain")),
+            space1,
+            contents,
+            space0,
+            context("\".\"", char('.')),
+            space0,
+        )),
+        |(_, name, _, _, _, _, _, contains, _, _, _)| Rule { name, contains },
+    )(input)
+}
+
+fn syntax_error(line: usize, text: &str, err: VerboseError<&str>) -> RuleError {
+    let (rest, expected) = err
+        .errors
+        .iter()
+        .find_map(|(rest, kind)| match kind {
+            VerboseErrorKind::Context(ctx) => Some((*rest, *ctx)),
+            _ => None,
+        })
+        .unwrap_or_else(|| match err.errors[0] {
+            (rest, VerboseErrorKind::Nom(ErrorKind::Eof)) => (rest, "end of line"),
+            (rest, VerboseErrorKind::Nom(ErrorKind::Space)) => (rest, "whitespace"),
+            (rest, _) => (rest, "valid rule"),
+        });
+
+    RuleError::Syntax {
+        line,
+        column: text[..text.len() - rest.len()].chars().count() + 1,
+        expected,
+    }
+}
+
+pub fn parse_rules(input: &str) -> Result<Vec<Rule<'_>>, ValidationError> {
+    let mut rules = vec![];
+    let mut errors = vec![];
+
+    for (idx, line) in input.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        match all_consuming(parse_rule)(line) {
+            Ok((_, rule)) => rules.push(rule),
+            Err(nom::Err::Error(e)) | Err(nom::Err::Failure(e)) => {
+                errors.push(syntax_error(idx + 1, line, e))
+            }
+            Err(nom::Err::Incomplete(_)) => unreachable!(),
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(rules)
+    } else {
+        Err(ValidationError(errors))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum RuleError {
+    Syntax {
+        line: usize,
+        column: usize,
+        expected: &'static str,
+    },
+    Cycle(Vec<String>),
+    Dangling {
+        bag: String,
+        missing: String,
+    },
+    Duplicate(String),
+    SelfContaining(String),
+}
+
+impl fmt::Display for RuleError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RuleError::Syntax {
+                line,
+                column,
+                expected,
+            } => write!(f, "{}:{}: expected {}", line, column, expected),
+            RuleError::Cycle(path) => write!(f, "cycle: {}", path.join(" -> ")),
+            RuleError::Dangling { bag, missing } => {
+                write!(f, "{} contains undefined bag {}", bag, missing)
+            }
+            RuleError::Duplicate(bag) => write!(f, "{} is defined more than once", bag),
+            RuleError::SelfContaining(bag) => write!(f, "{} contains itself", bag),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ValidationError(pub Vec<RuleError>);
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (idx, err) in self.0.iter().enumerate() {
+            if idx > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{}", err)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ValidationError {}
+
+fn find_cycles(rules: &[Rule<'_>], index: &HashMap<&str, usize>) -> Vec<Vec<String>> {
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    enum State {
+        New,
+        OnStack,
+        Done,
+    }
+
+    let mut state = vec![State::New; rules.len()];
+    let mut cycles = vec![];
+
+    // iterative so that deep or cyclic rule sets can't blow the stack
+    for root in 0..rules.len() {
+        if state[root] != State::New {
+            continue;
+        }
+        let mut stack = vec![(root, 0)];
+        state[root] = State::OnStack;
+
+        while let Some((node, next_child)) = stack.last_mut() {
+            let node = *node;
+            let child = match rules[node].contains.get(*next_child) {
+                Some((_, name)) => index.get(name).cloned(),
+                None => {
+                    state[node] = State::Done;
+                    stack.pop();
+                    continue;
+                }
+            };
+            *next_child += 1;
+
+            // dangling references and self containment are reported separately
+            let child = match child {
+                Some(child) if child != node => child,
+                _ => continue,
+            };
+
+            match state[child] {
+                State::New => {
+                    state[child] = State::OnStack;
+                    stack.push((child, 0));
+                }
+                State::OnStack => {
+                    let start = stack.iter().position(|(n, _)| *n == child).unwrap();
+                    let mut path: Vec<String> = stack[start..]
+                        .iter()
+                        .map(|(n, _)| rules[*n].name.to_owned())
+                        .collect();
+                    path.push(rules[child].name.to_owned());
+                    cycles.push(path);
+                }
+                State::Done => {}
+            }
+        }
+    }
+
+    cycles
+}
+
+pub fn validate(rules: &[Rule<'_>]) -> Result<(), ValidationError> {
+    let mut errors = vec![];
+    let mut index = HashMap::new();
+
+    for (idx, rule) in rules.iter().enumerate() {
+        if index.contains_key(rule.name) {
+            errors.push(RuleError::Duplicate(rule.name.to_owned()));
+        } else {
+            index.insert(rule.name, idx);
+        }
+    }
+
+    for rule in rules {
+        for (_, child) in &rule.contains {
+            if *child == rule.name {
+                errors.push(RuleError::SelfContaining(rule.name.to_owned()));
+            } else if !index.contains_key(child) {
+                errors.push(RuleError::Dangling {
+                    bag: rule.name.to_owned(),
+                    missing: (*child).to_owned(),
+                });
+            }
+        }
+    }
+
+    errors.extend(find_cycles(rules, &index).into_iter().map(RuleError::Cycle));
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(ValidationError(errors))
+    }
+}
+
+fn input_generator(input: &str) -> Result<Vec<Rule<'_>>, ValidationError> {
+    let rules = parse_rules(input)?;
+    validate(&rules)?;
+    Ok(rules)
+}
+
+fn solve_part1(input: &[Rule<'_>]) -> usize {
+    let mut parents: HashMap<&str, Vec<&str>> = HashMap::new();
+
+    for rule in input {
+        for (_, child) in &rule.contains {
+            let p = parents.entry(*child).or_insert(vec![]);
+            p.push(rule.name);
+        }
+    }
+
+    let mut visited = HashSet::new();
+
+    fn traverse<'a>(
+        visited: &mut HashSet<&'a str>,
+        parents: &HashMap<&'a str, Vec<&'a str>>,
+        node: &'a str,
+    ) {
+        if visited.contains(node) {
+            return;
+        }
+        visited.insert(node);
+        if let Some(parent_list) = parents.get(node) {
+            for parent in parent_list {
+                traverse(visited, parents, parent);
+            }
+        }
+    };
+
+    traverse(&mut visited, &parents, "shiny gold");
+
+    visited.len() - 1
+}
+
+// only for rules that passed validate, a cycle would keep this going forever
+fn count_contents<C: Count>(rules: &[Rule<'_>], root: &str) -> Result<C, Overflow> {
+    let children: HashMap<_, _> = rules.iter().map(|x| (x.name, &x.contains)).collect();
+    let mut inside: HashMap<&str, C> = HashMap::new();
+    let mut stack = vec![root];
+
+    // post-order without recursion so that deep rule chains can't blow the stack
+    while let Some(node) = stack.last().cloned() {
+        let content = children.get(node).map(|x| &x[..]).unwrap_or(&[]);
+        let pending: Vec<_> = content
+            .iter()
+            .map(|(_, child)| *child)
+            .filter(|child| !inside.contains_key(child))
+            .collect();
+
+        if pending.is_empty() {
+            let mut total = C::zero();
+            for (repetition, child) in content {
+                let bags = C::one().add(&inside[child])?;
+                total = total.add(&C::from_usize(*repetition).mul(&bags)?)?;
+            }
+            inside.insert(node, total);
+            stack.pop();
+        } else {
+            stack.extend(pending);
+        }
+    }
+
+    Ok(inside.remove(root).unwrap())
+}
+
+fn solve_part2(input: &[Rule<'_>]) -> Result<Counter, Overflow> {
+    count_contents(input, "shiny gold")
+}
+
+pub struct Day7;
+
+impl Solver for Day7 {
+    type Input<'a> = Vec<Rule<'a>>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Vec<Rule<'a>>, Box<dyn Error>> {
+        Ok(input_generator(input)?)
+    }
+
+    fn part1(&self, input: &Vec<Rule<'_>>) -> Answer {
+        Ok(Box::new(solve_part1(input)))
+    }
+
+    fn part2(&self, input: &Vec<Rule<'_>>) -> Answer {
+        Ok(Box::new(solve_part2(input)?))
+    }
+}
+
+aoc_solver!(day7, Day7, borrowed);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../tests/fixtures/2020/day7/example.txt");
+
+    #[test]
+    fn part1() {
+        let rules = input_generator(EXAMPLE).unwrap();
+
+        assert_eq!(solve_part1(&rules), 4);
+    }
+
+    #[test]
+    fn part2_example1() {
+        let rules = input_generator(EXAMPLE).unwrap();
+
+        assert_eq!(solve_part2(&rules).unwrap(), Counter::from_usize(32));
+    }
+
+    #[test]
+    fn part2_example2() {
+        let rules =
+            input_generator(include_str!("../../tests/fixtures/2020/day7/deep.txt")).unwrap();
+
+        assert_eq!(solve_part2(&rules).unwrap(), Counter::from_usize(126));
+    }
+
+    #[test]
+    fn validate_cycle() {
+        let err = input_generator(
+            "light red bags contain 1 bright white bag.
+bright white bags contain 2 muted yellow bags.
+muted yellow bags contain 1 light red bag, 1 faded blue bag.
+faded blue bags contain no other bags.",
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err,
+            ValidationError(vec![RuleError::Cycle(vec![
+                "light red".to_owned(),
+                "bright white".to_owned(),
+                "muted yellow".to_owned(),
+                "light red".to_owned(),
+            ])])
+        );
+    }
+
+    #[test]
+    fn validate_errors() {
+        let err = input_generator(
+            "light red bags contain 1 light red bag, 2 muted yellow bags.
+faded blue bags contain no other bags.
+faded blue bags contain 1 dotted black bag.",
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err,
+            ValidationError(vec![
+                RuleError::Duplicate("faded blue".to_owned()),
+                RuleError::SelfContaining("light red".to_owned()),
+                RuleError::Dangling {
+                    bag: "light red".to_owned(),
+                    missing: "muted yellow".to_owned(),
+                },
+                RuleError::Dangling {
+                    bag: "faded blue".to_owned(),
+                    missing: "dotted black".to_owned(),
+                },
+            ])
+        );
+    }
+
+    #[test]
+    fn parse() {
+        let (rest, rule) = parse_rule(
+            "  pale  chartreuse orange bags contain 1 shiny gold bag ,2 dark olive bags . ",
+        )
+        .unwrap();
+
+        assert_eq!(rest, "");
+        assert_eq!(
+            rule,
+            Rule {
+                name: "pale  chartreuse orange",
+                contains: vec![(1, "shiny gold"), (2, "dark olive")],
+            }
+        );
+
+        let (_, rule) = parse_rule("faded blue bag contain no other bag.").unwrap();
+        assert_eq!(
+            rule,
+            Rule {
+                name: "faded blue",
+                contains: vec![],
+            }
+        );
+    }
+
+    #[test]
+    fn parse_errors() {
+        let err = input_generator(
+            "light red bags contain 1 bright white bag.
+bright white boxes contain no other bags.
+dark orange bags contain 3 bright white bags 4 muted yellow bags.
+
+muted yellow bags contain two shiny gold bags.
+shiny gold bags contain no other bags",
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err,
+            ValidationError(vec![
+                RuleError::Syntax {
+                    line: 2,
+                    column: 20,
+                    expected: "\"bag\" or \"bags\"",
+                },
+                RuleError::Syntax {
+                    line: 3,
+                    column: 46,
+                    expected: "\".\"",
+                },
+                RuleError::Syntax {
+                    line: 5,
+                    column: 27,
+                    expected: "count",
+                },
+                RuleError::Syntax {
+                    line: 6,
+                    column: 38,
+                    expected: "\".\"",
+                },
+            ])
+        );
+    }
+
+    #[test]
+    fn part2_overflow() {
+        let shade = |x: u8| {
+            format!(
+                "{}{} blue",
+                (b'a' + x / 26) as char,
+                (b'a' + x % 26) as char
+            )
+        };
+        let chain: String = (0..100)
+            .map(|x| format!("{} bags contain 2 {} bags.\n", shade(x), shade(x + 1)))
+            .chain(std::iter::once(format!(
+                "{} bags contain no other bags.",
+                shade(100)
+            )))
+            .collect();
+        let rules = input_generator(&chain).unwrap();
+
+        assert_eq!(count_contents::<u64>(&rules, "aa blue"), Err(Overflow));
+        assert_eq!(
+            count_contents::<u128>(&rules, "aa blue"),
+            Ok((1 << 101) - 2)
+        );
+    }
+}
@@ -0,0 +1,237 @@
+use super::Rule;
+use serde_json::{json, Map, Value};
+use std::collections::HashMap;
+use std::collections::VecDeque;
+use std::fmt::Write;
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Direction {
+    Contents,
+    Containers,
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub struct BagGraph {
+    nodes: Vec<String>,
+    // (container, content, count)
+    edges: Vec<(usize, usize, usize)>,
+}
+
+impl BagGraph {
+    pub fn new(rules: &[Rule<'_>]) -> BagGraph {
+        let mut nodes = vec![];
+        let mut index = HashMap::new();
+        let mut edges = vec![];
+
+        let mut node = |name: &str| {
+            *index.entry(name.to_owned()).or_insert_with(|| {
+                nodes.push(name.to_owned());
+                nodes.len() - 1
+            })
+        };
+
+        for rule in rules {
+            let from = node(rule.name);
+            for (count, child) in &rule.contains {
+                edges.push((from, node(child), *count));
+            }
+        }
+
+        BagGraph { nodes, edges }
+    }
+
+    pub fn subgraph(&self, root: &str, direction: Direction) -> Option<BagGraph> {
+        let root = self.nodes.iter().position(|x| x == root)?;
+
+        let mut reached = vec![false; self.nodes.len()];
+        let mut queue = VecDeque::new();
+        reached[root] = true;
+        queue.push_back(root);
+
+        while let Some(node) = queue.pop_front() {
+            for (from, to, _) in &self.edges {
+                let next = match direction {
+                    Direction::Contents if *from == node => *to,
+                    Direction::Containers if *to == node => *from,
+                    _ => continue,
+                };
+                if !reached[next] {
+                    reached[next] = true;
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        let mut renumbered = vec![None; self.nodes.len()];
+        let mut nodes = vec![];
+        for (idx, name) in self.nodes.iter().enumerate() {
+            if reached[idx] {
+                renumbered[idx] = Some(nodes.len());
+                nodes.push(name.to_owned());
+            }
+        }
+
+        let edges = self
+            .edges
+            .iter()
+            .filter_map(|(from, to, count)| Some((renumbered[*from]?, renumbered[*to]?, *count)))
+            .collect();
+
+        Some(BagGraph { nodes, edges })
+    }
+
+    pub fn to_dot(&self) -> String {
+        fn quote(s: &str) -> String {
+            format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""))
+        }
+
+        let mut out = String::from("digraph bags {\n");
+        for node in &self.nodes {
+            writeln!(out, "    {};", quote(node)).unwrap();
+        }
+        for (from, to, count) in &self.edges {
+            writeln!(
+                out,
+                "    {} -> {} [label=\"{}\"];",
+                quote(&self.nodes[*from]),
+                quote(&self.nodes[*to]),
+                count
+            )
+            .unwrap();
+        }
+        out.push_str("}\n");
+        out
+    }
+
+    pub fn to_json(&self) -> String {
+        let mut adjacency: Map<String, Value> = self
+            .nodes
+            .iter()
+            .map(|node| (node.to_owned(), json!([])))
+            .collect();
+
+        for (from, to, count) in &self.edges {
+            if let Some(Value::Array(children)) = adjacency.get_mut(&self.nodes[*from]) {
+                children.push(json!({ "bag": self.nodes[*to], "count": count }));
+            }
+        }
+
+        serde_json::to_string_pretty(&adjacency).unwrap()
+    }
+
+    pub fn to_graphml(&self) -> String {
+        fn escape(s: &str) -> String {
+            s.replace('&', "&amp;")
+                .replace('<', "&lt;")
+                .replace('>', "&gt;")
+                .replace('"', "&quot;")
+        }
+
+        let mut out = String::from(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>
+<graphml xmlns=\"http://graphml.graphdrawing.org/xmlns\">
+  <key id=\"count\" for=\"edge\" attr.name=\"count\" attr.type=\"int\"/>
+  <graph id=\"bags\" edgedefault=\"directed\">
+",
+        );
+        for node in &self.nodes {
+            writeln!(out, "    <node id=\"{}\"/>", escape(node)).unwrap();
+        }
+        for (from, to, count) in &self.edges {
+            writeln!(
+                out,
+                "    <edge source=\"{}\" target=\"{}\"><data key=\"count\">{}</data></edge>",
+                escape(&self.nodes[*from]),
+                escape(&self.nodes[*to]),
+                count
+            )
+            .unwrap();
+        }
+        out.push_str("  </graph>\n</graphml>\n");
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::parse_rules;
+    use super::*;
+
+    fn graph() -> BagGraph {
+        BagGraph::new(
+            &parse_rules(
+                "bright white bags contain 1 shiny gold bag.
+muted yellow bags contain 2 shiny gold bags, 9 faded blue bags.
+shiny gold bags contain 1 dark olive bag.
+dark olive bags contain 3 faded blue bags.
+faded blue bags contain no other bags.",
+            )
+            .unwrap(),
+        )
+    }
+
+    #[test]
+    fn dot() {
+        assert_eq!(
+            graph()
+                .subgraph("shiny gold", Direction::Contents)
+                .unwrap()
+                .to_dot(),
+            "digraph bags {
+    \"shiny gold\";
+    \"faded blue\";
+    \"dark olive\";
+    \"shiny gold\" -> \"dark olive\" [label=\"1\"];
+    \"dark olive\" -> \"faded blue\" [label=\"3\"];
+}
+"
+        );
+    }
+
+    #[test]
+    fn subgraph_containers() {
+        let sub = graph()
+            .subgraph("shiny gold", Direction::Containers)
+            .unwrap();
+
+        assert_eq!(
+            sub.nodes,
+            vec!["bright white", "shiny gold", "muted yellow"]
+        );
+        assert_eq!(sub.edges, vec![(0, 1, 1), (2, 1, 2)]);
+        assert_eq!(graph().subgraph("dotted black", Direction::Contents), None);
+    }
+
+    #[test]
+    fn json() {
+        let value: Value = serde_json::from_str(
+            &graph()
+                .subgraph("dark olive", Direction::Contents)
+                .unwrap()
+                .to_json(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            value,
+            json!({
+                "dark olive": [{ "bag": "faded blue", "count": 3 }],
+                "faded blue": [],
+            })
+        );
+    }
+
+    #[test]
+    fn graphml() {
+        let out = graph()
+            .subgraph("dark olive", Direction::Contents)
+            .unwrap()
+            .to_graphml();
+
+        assert!(out.contains("<node id=\"dark olive\"/>"));
+        assert!(out.contains(
+            "<edge source=\"dark olive\" target=\"faded blue\"><data key=\"count\">3</data></edge>"
+        ));
+        assert!(out.ends_with("</graphml>\n"));
+    }
+}
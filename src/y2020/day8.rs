@@ -1,8 +1,9 @@
-use aoc_runner_derive::{aoc, aoc_generator};
+use crate::solver::{aoc_solver, Answer, Solver};
 use std::collections::HashSet;
+use std::error::Error;
 
 #[derive(Clone)]
-enum Instruction {
+pub enum Instruction {
     Nop(i32),
     Acc(i32),
     Jmp(i32),
@@ -14,7 +15,6 @@ fn parse_num(x: &str) -> i32 {
         .unwrap()
 }
 
-#[aoc_generator(day8)]
 fn input_generator(input: &str) -> Vec<Instruction> {
     input
         .lines()
@@ -67,7 +67,6 @@ fn emulate(program: &[Instruction]) -> EmulationResult {
     }
 }
 
-#[aoc(day8, part1)]
 fn solve_part1(input: &[Instruction]) -> i32 {
     match emulate(input) {
         EmulationResult::InfiniteLoop(x) => x,
@@ -75,7 +74,6 @@ fn solve_part1(input: &[Instruction]) -> i32 {
     }
 }
 
-#[aoc(day8, part2)]
 fn solve_part2(input: &[Instruction]) -> i32 {
     for iptr in 0..input.len() {
         let mut program: Vec<_> = input.iter().map(|x| x.clone()).collect();
@@ -96,40 +94,38 @@ fn solve_part2(input: &[Instruction]) -> i32 {
     panic!("Should have found solution")
 }
 
+pub struct Day8;
+
+impl Solver for Day8 {
+    type Input<'a> = Vec<Instruction>;
+
+    fn parse(&self, input: &str) -> Result<Vec<Instruction>, Box<dyn Error>> {
+        Ok(input_generator(input))
+    }
+
+    fn part1(&self, input: &Vec<Instruction>) -> Answer {
+        Ok(Box::new(solve_part1(input)))
+    }
+
+    fn part2(&self, input: &Vec<Instruction>) -> Answer {
+        Ok(Box::new(solve_part2(input)))
+    }
+}
+
+aoc_solver!(day8, Day8);
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    const EXAMPLE: &str = include_str!("../../tests/fixtures/2020/day8/example.txt");
+
     #[test]
     fn part1() {
-        let rules = input_generator(
-            "nop +0
-acc +1
-jmp +4
-acc +3
-jmp -3
-acc -99
-acc +1
-jmp -4
-acc +6",
-        );
-
-        assert_eq!(solve_part1(&rules), 5);
+        assert_eq!(solve_part1(&input_generator(EXAMPLE)), 5);
     }
     #[test]
     fn part2() {
-        let rules = input_generator(
-            "nop +0
-acc +1
-jmp +4
-acc +3
-jmp -3
-acc -99
-acc +1
-jmp -4
-acc +6",
-        );
-
-        assert_eq!(solve_part2(&rules), 8);
+        assert_eq!(solve_part2(&input_generator(EXAMPLE)), 8);
     }
 }
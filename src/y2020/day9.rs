@@ -0,0 +1,180 @@
+use crate::solver::{aoc_solver, Answer, Solver};
+use crate::util::numbers;
+use std::error::Error;
+
+fn input_generator(input: &str) -> Vec<usize> {
+    numbers(input)
+}
+
+fn check_summing(src: &[usize], target: usize) -> bool {
+    for (idx, x) in src.iter().enumerate() {
+        for y in src.iter().skip(idx + 1) {
+            if x != y && x + y == target {
+                return true;
+            }
+        }
+    }
+
+    return false;
+}
+
+fn find_first_non_summing(lookbehind: usize, input: &[usize]) -> usize {
+    for idx in lookbehind..input.len() {
+        if !check_summing(&input[idx - lookbehind..idx], input[idx]) {
+            return input[idx];
+        }
+    }
+    panic!("No non-summing")
+}
+
+fn find_range_with_sum(target_sum: usize, input: &[usize]) -> &[usize] {
+    for (idx, fst) in input.iter().enumerate() {
+        let mut sum = *fst;
+        for (last_idx, next) in input.iter().enumerate().skip(idx + 1) {
+            sum += next;
+            if sum == target_sum {
+                return &input[idx..=last_idx];
+            }
+        }
+    }
+
+    panic!("No continuous range with sum found");
+}
+
+fn find_weakness(lookbehind: usize, input: &[usize]) -> usize {
+    let non_summing = find_first_non_summing(lookbehind, input);
+    let range = find_range_with_sum(non_summing, input);
+    range.iter().min().unwrap() + range.iter().max().unwrap()
+}
+
+fn solve_part1(input: &[usize]) -> usize {
+    find_first_non_summing(25, input)
+}
+
+fn solve_part2(input: &[usize]) -> usize {
+    find_weakness(25, input)
+}
+
+pub struct Day9;
+
+impl Solver for Day9 {
+    type Input<'a> = Vec<usize>;
+
+    fn parse(&self, input: &str) -> Result<Vec<usize>, Box<dyn Error>> {
+        Ok(input_generator(input))
+    }
+
+    fn part1(&self, input: &Vec<usize>) -> Answer {
+        Ok(Box::new(solve_part1(input)))
+    }
+
+    fn part2(&self, input: &Vec<usize>) -> Answer {
+        Ok(Box::new(solve_part2(input)))
+    }
+}
+
+aoc_solver!(day9, Day9);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    use std::collections::HashSet;
+
+    // the puzzle's rules written out as directly as possible: a number is
+    // fine if two different values in the window before it add up to it
+    fn naive_first_non_summing(lookbehind: usize, input: &[usize]) -> Option<usize> {
+        (lookbehind..input.len())
+            .map(|idx| input[idx])
+            .zip(input.windows(lookbehind))
+            .find_map(|(target, window)| {
+                let window: HashSet<_> = window.iter().collect();
+                let sums = window.iter().any(|x| {
+                    target >= **x && target - **x != **x && window.contains(&(target - **x))
+                });
+                if sums {
+                    None
+                } else {
+                    Some(target)
+                }
+            })
+    }
+
+    // the first range of at least two numbers by where it starts and then
+    // where it ends, from the differences of running totals
+    fn naive_range_with_sum(target_sum: usize, input: &[usize]) -> Option<&[usize]> {
+        let mut totals = vec![0];
+        for x in input {
+            totals.push(totals.last().unwrap() + x);
+        }
+        (0..input.len())
+            .flat_map(|start| (start + 2..=input.len()).map(move |end| (start, end)))
+            .find(|(start, end)| totals[*end] - totals[*start] == target_sum)
+            .map(|(start, end)| &input[start..end])
+    }
+
+    #[test]
+    fn part1() {
+        assert_eq!(
+            find_first_non_summing(
+                5,
+                &[
+                    35, 20, 15, 25, 47, 40, 62, 55, 65, 95, 102, 117, 150, 182, 127, 219, 299, 277,
+                    309, 576,
+                ]
+            ),
+            127
+        );
+    }
+    #[test]
+    fn part2() {
+        assert_eq!(
+            find_weakness(
+                5,
+                &[
+                    35, 20, 15, 25, 47, 40, 62, 55, 65, 95, 102, 117, 150, 182, 127, 219, 299, 277,
+                    309, 576,
+                ]
+            ),
+            62
+        );
+    }
+
+    // the solvers panic without an answer, so one is always planted: a
+    // number too big to be a sum of the others at the end, and a target that
+    // some range adds up to
+    proptest! {
+        #[test]
+        fn non_summing_matches_naive(
+            lookbehind in 1usize..6,
+            input in prop::collection::vec(1usize..60, 0..40),
+        ) {
+            let mut input = input;
+            input.extend(vec![1000; lookbehind + 1]);
+            prop_assert_eq!(
+                Some(find_first_non_summing(lookbehind, &input)),
+                naive_first_non_summing(lookbehind, &input)
+            );
+        }
+
+        #[test]
+        fn range_matches_naive(
+            (input, start, len) in prop::collection::vec(1usize..40, 2..30)
+                .prop_flat_map(|input| {
+                    let len = input.len();
+                    (Just(input), 0..len - 1)
+                })
+                .prop_flat_map(|(input, start)| {
+                    let len = input.len();
+                    (Just(input), Just(start), 2..=len - start)
+                }),
+        ) {
+            let target_sum = input[start..start + len].iter().sum();
+            prop_assert_eq!(
+                Some(find_range_with_sum(target_sum, &input)),
+                naive_range_with_sum(target_sum, &input)
+            );
+        }
+    }
+}
@@ -0,0 +1,802 @@
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use std::collections::{HashMap, HashSet};
+use std::fmt::Write;
+
+// a generated puzzle input along with its answers, where they are known
+#[derive(Clone, Debug, PartialEq)]
+pub struct Puzzle {
+    pub input: String,
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+impl Puzzle {
+    fn new(input: String, part1: impl ToString, part2: Option<impl ToString>) -> Puzzle {
+        Puzzle {
+            input,
+            part1: Some(part1.to_string()),
+            part2: part2.map(|x| x.to_string()),
+        }
+    }
+}
+
+// roughly the size of a real input, in each day's own unit
+pub fn typical_size(day: u32) -> Option<usize> {
+    Some(match day {
+        1 => 200,
+        2 => 1000,
+        3 => 323,
+        4 => 290,
+        5 => 800,
+        6 => 490,
+        7 => 594,
+        8 => 640,
+        9 => 1000,
+        10 => 100,
+        11 => 90,
+        12 => 780,
+        13 => 30,
+        _ => return None,
+    })
+}
+
+// the same seed and size always give the same puzzle
+pub fn generate(day: u32, seed: u64, size: usize) -> Option<Puzzle> {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let rng = &mut rng;
+
+    Some(match day {
+        1 => day1(rng, size),
+        2 => day2(rng, size),
+        3 => day3(rng, size),
+        4 => day4(rng, size),
+        5 => day5(rng, size),
+        6 => day6(rng, size),
+        7 => day7(rng, size),
+        8 => day8(rng, size),
+        9 => day9(rng, size),
+        10 => day10(rng, size),
+        11 => day11(rng, size),
+        12 => day12(rng, size),
+        13 => day13(rng, size),
+        _ => return None,
+    })
+}
+
+fn lines<T: ToString>(items: impl IntoIterator<Item = T>) -> String {
+    items.into_iter().map(|x| x.to_string() + "\n").collect()
+}
+
+const EXPENSES: u32 = 2020;
+
+// how many other entries x pairs with to reach 2020
+fn pairs_with(entries: &HashSet<u32>, x: u32) -> usize {
+    match EXPENSES.checked_sub(x) {
+        Some(y) if y != x && entries.contains(&y) => 1,
+        _ => 0,
+    }
+}
+
+// the same for triples, where each one is counted in both orders
+fn triples_with(entries: &HashSet<u32>, x: u32) -> usize {
+    entries
+        .iter()
+        .filter(|y| **y != x)
+        .filter(|y| {
+            matches!(EXPENSES.checked_sub(x + *y), Some(z) if z != x && z != **y && entries.contains(&z))
+        })
+        .count()
+}
+
+// size entries with one planted pair and one planted triple. the rest sit
+// above 1010 so that they can't make up a pair on their own
+fn day1(rng: &mut StdRng, size: usize) -> Puzzle {
+    let size = size.clamp(5, 900);
+
+    let (entries, pair, triple) = loop {
+        let a = rng.gen_range(1..1010);
+        let b = EXPENSES - a;
+        let t1 = rng.gen_range(1..700);
+        let t2 = rng.gen_range(1..700);
+        let t3 = EXPENSES - t1 - t2;
+
+        let mut entries = HashSet::new();
+        let mut planted = true;
+        for (x, pairs, triples) in &[(a, 0, 0), (b, 1, 0), (t1, 0, 0), (t2, 0, 0), (t3, 0, 2)] {
+            planted &= !entries.contains(x)
+                && pairs_with(&entries, *x) == *pairs
+                && triples_with(&entries, *x) == *triples;
+            entries.insert(*x);
+        }
+        if planted && t1 != t2 {
+            break (entries, a * b, t1 * t2 * t3);
+        }
+    };
+
+    let mut entries = entries;
+    while entries.len() < size {
+        let x = rng.gen_range(1011..=EXPENSES);
+        if !entries.contains(&x) && pairs_with(&entries, x) == 0 && triples_with(&entries, x) == 0 {
+            entries.insert(x);
+        }
+    }
+
+    let mut entries: Vec<_> = entries.into_iter().collect();
+    entries.sort_unstable();
+    entries.shuffle(rng);
+    Puzzle::new(lines(entries), pair, Some(triple))
+}
+
+fn day2(rng: &mut StdRng, size: usize) -> Puzzle {
+    let (mut part1, mut part2) = (0, 0);
+    let mut input = String::new();
+
+    for _ in 0..size {
+        let letter = rng.gen_range(b'a'..=b'z');
+        let len = rng.gen_range(2..=20);
+        let lo = rng.gen_range(1..len);
+        let hi = rng.gen_range(lo + 1..=len);
+        let password: Vec<u8> = (0..len)
+            .map(|_| {
+                if rng.gen_bool(0.3) {
+                    letter
+                } else {
+                    rng.gen_range(b'a'..=b'z')
+                }
+            })
+            .collect();
+
+        let count = password.iter().filter(|x| **x == letter).count();
+        part1 += (lo..=hi).contains(&count) as usize;
+        part2 += ((password[lo - 1] == letter) != (password[hi - 1] == letter)) as usize;
+
+        writeln!(
+            input,
+            "{}-{} {}: {}",
+            lo,
+            hi,
+            letter as char,
+            String::from_utf8(password).unwrap()
+        )
+        .unwrap();
+    }
+
+    Puzzle::new(input, part1, Some(part2))
+}
+
+fn day3(rng: &mut StdRng, size: usize) -> Puzzle {
+    let width = 31;
+    let trees: Vec<Vec<bool>> = (0..size.max(1))
+        .map(|_| (0..width).map(|_| rng.gen_bool(0.25)).collect())
+        .collect();
+
+    let slope = |right: usize, down: usize| {
+        trees
+            .iter()
+            .step_by(down)
+            .enumerate()
+            .filter(|(idx, row)| row[idx * right % width])
+            .count()
+    };
+    let part2 = [(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)]
+        .iter()
+        .map(|(right, down)| slope(*right, *down))
+        .product::<usize>();
+
+    let input = lines(trees.iter().map(|row| {
+        row.iter()
+            .map(|x| if *x { '#' } else { '.' })
+            .collect::<String>()
+    }));
+    Puzzle::new(input, slope(3, 1), Some(part2))
+}
+
+const REQUIRED: [&str; 7] = ["byr", "iyr", "eyr", "hgt", "hcl", "ecl", "pid"];
+
+fn digits(rng: &mut StdRng, len: usize) -> String {
+    (0..len)
+        .map(|_| rng.gen_range(b'0'..=b'9') as char)
+        .collect()
+}
+
+fn field(rng: &mut StdRng, name: &str, valid: bool) -> String {
+    let pick = |rng: &mut StdRng, options: &[&str]| options.choose(rng).unwrap().to_string();
+    match (name, valid) {
+        ("byr", true) => rng.gen_range(1920..=2002).to_string(),
+        ("byr", false) => pick(rng, &["1900", "1919", "2003", "2020"]),
+        ("iyr", true) => rng.gen_range(2010..=2020).to_string(),
+        ("iyr", false) => pick(rng, &["2000", "2009", "2021", "2030"]),
+        ("eyr", true) => rng.gen_range(2020..=2030).to_string(),
+        ("eyr", false) => pick(rng, &["2010", "2019", "2031", "2040"]),
+        ("hgt", true) if rng.gen() => format!("{}cm", rng.gen_range(150..=193)),
+        ("hgt", true) => format!("{}in", rng.gen_range(59..=76)),
+        ("hgt", false) => pick(rng, &["149cm", "194cm", "58in", "77in", "170", "65"]),
+        ("hcl", true) => format!("#{:06x}", rng.gen_range(0..0x1000000)),
+        ("hcl", false) => pick(rng, &["#12345g", "123abc", "#12345", "#1234567", "z"]),
+        ("ecl", true) => pick(rng, &["amb", "blu", "brn", "gry", "grn", "hzl", "oth"]),
+        ("ecl", false) => pick(rng, &["xry", "gmt", "blue", "zzz"]),
+        ("pid", true) => digits(rng, 9),
+        ("pid", false) => {
+            let len = *[8, 10].choose(rng).unwrap();
+            digits(rng, len)
+        }
+        _ => rng.gen_range(100..350).to_string(),
+    }
+}
+
+// each passport is valid, missing a required field, or has one bad value
+fn day4(rng: &mut StdRng, size: usize) -> Puzzle {
+    let (mut part1, mut part2) = (0, 0);
+    let mut passports = vec![];
+
+    for _ in 0..size {
+        let mut names: Vec<_> = REQUIRED.to_vec();
+        if rng.gen() {
+            names.push("cid");
+        }
+        let kind = rng.gen_range(0..3);
+        let mut bad = None;
+        match kind {
+            0 => {
+                part1 += 1;
+                part2 += 1;
+            }
+            1 => {
+                names.remove(rng.gen_range(0..REQUIRED.len()));
+            }
+            _ => {
+                part1 += 1;
+                bad = Some(*REQUIRED.choose(rng).unwrap());
+            }
+        }
+        names.shuffle(rng);
+
+        let mut passport = String::new();
+        for (idx, name) in names.iter().enumerate() {
+            if idx > 0 {
+                passport.push(if rng.gen_bool(0.3) { '\n' } else { ' ' });
+            }
+            let value = field(rng, name, bad != Some(*name));
+            write!(passport, "{}:{}", name, value).unwrap();
+        }
+        passports.push(passport);
+    }
+
+    Puzzle::new(passports.join("\n\n") + "\n", part1, Some(part2))
+}
+
+// a run of consecutive seat ids with one missing from the middle
+fn day5(rng: &mut StdRng, size: usize) -> Puzzle {
+    let size = size.clamp(2, 1000);
+    let first = rng.gen_range(0..1024 - size);
+    let missing = rng.gen_range(first + 1..first + size);
+    let mut ids: Vec<usize> = (first..=first + size).filter(|x| *x != missing).collect();
+    ids.shuffle(rng);
+
+    let seat = |id: usize| -> String {
+        let row = (0..7)
+            .rev()
+            .map(|bit| if id >> (bit + 3) & 1 == 1 { 'B' } else { 'F' });
+        let col = (0..3)
+            .rev()
+            .map(|bit| if id >> bit & 1 == 1 { 'R' } else { 'L' });
+        row.chain(col).collect()
+    };
+
+    Puzzle::new(
+        lines(ids.iter().map(|x| seat(*x))),
+        first + size,
+        Some(missing),
+    )
+}
+
+fn day6(rng: &mut StdRng, size: usize) -> Puzzle {
+    let (mut part1, mut part2) = (0, 0);
+    let mut groups = vec![];
+
+    for _ in 0..size {
+        let common: u32 = rng.gen::<u32>() & rng.gen::<u32>() & rng.gen::<u32>() & 0x3ffffff;
+        let people: Vec<u32> = (0..rng.gen_range(1..=5))
+            .map(|_| (common | rng.gen::<u32>() & rng.gen::<u32>() & 0x3ffffff).max(1))
+            .collect();
+
+        part1 += people.iter().fold(0, |acc, x| acc | x).count_ones();
+        part2 += people.iter().fold(!0, |acc, x| acc & x).count_ones();
+
+        let mut group = String::new();
+        for person in people {
+            let mut letters: Vec<char> = (0..26)
+                .filter(|bit| person >> bit & 1 == 1)
+                .map(|bit| (b'a' + bit as u8) as char)
+                .collect();
+            letters.shuffle(rng);
+            group.extend(letters);
+            group.push('\n');
+        }
+        groups.push(group);
+    }
+
+    Puzzle::new(groups.join("\n"), part1, Some(part2))
+}
+
+const SHADES: [&str; 8] = [
+    "light", "dark", "bright", "muted", "faded", "dotted", "pale", "posh",
+];
+
+// letters only, and never a word the rule grammar treats specially
+fn hue(mut n: usize) -> String {
+    let mut word = "hue".to_owned();
+    loop {
+        word.push((b'a' + (n % 26) as u8) as char);
+        n /= 26;
+        if n == 0 {
+            return word;
+        }
+    }
+}
+
+// bags only ever contain bags later in the list, so the rules form a dag
+fn day7(rng: &mut StdRng, size: usize) -> Puzzle {
+    let size = size.max(2);
+    let gold = rng.gen_range(size / 4..=size / 2);
+    let names: Vec<String> = (0..size)
+        .map(|idx| match idx {
+            idx if idx == gold => "shiny gold".to_owned(),
+            idx => format!("{} {}", SHADES[idx % SHADES.len()], hue(idx)),
+        })
+        .collect();
+
+    let mut contents: Vec<Vec<(usize, usize)>> = (0..size)
+        .map(|idx| {
+            let kinds = if idx + 1 == size {
+                0
+            } else {
+                rng.gen_range(0..=3)
+            };
+            let mut inner: Vec<usize> = (0..kinds).map(|_| rng.gen_range(idx + 1..size)).collect();
+            inner.sort_unstable();
+            inner.dedup();
+            inner
+                .into_iter()
+                .map(|child| (rng.gen_range(1..=4), child))
+                .collect()
+        })
+        .collect();
+    for _ in 0..gold.min(5) {
+        let parent = rng.gen_range(0..gold);
+        if contents[parent].iter().all(|(_, child)| *child != gold) {
+            contents[parent].push((rng.gen_range(1..=4), gold));
+        }
+    }
+
+    // a bag holds shiny gold when any of the bags inside does
+    let mut holds = vec![false; size];
+    for idx in (0..gold).rev() {
+        holds[idx] = contents[idx]
+            .iter()
+            .any(|(_, child)| *child == gold || holds[*child]);
+    }
+    let part1 = holds.iter().filter(|x| **x).count();
+
+    let mut inside: Vec<Option<u128>> = vec![Some(0); size];
+    for idx in (gold..size).rev() {
+        inside[idx] = contents[idx].iter().try_fold(0u128, |acc, (count, child)| {
+            let each = inside[*child]?.checked_add(1)?;
+            acc.checked_add(each.checked_mul(*count as u128)?)
+        });
+    }
+
+    let mut rules: Vec<String> = contents
+        .iter()
+        .enumerate()
+        .map(|(idx, content)| {
+            let content = if content.is_empty() {
+                "no other bags".to_owned()
+            } else {
+                content
+                    .iter()
+                    .map(|(count, child)| {
+                        let plural = if *count == 1 { "" } else { "s" };
+                        format!("{} {} bag{}", count, names[*child], plural)
+                    })
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            };
+            format!("{} bags contain {}.", names[idx], content)
+        })
+        .collect();
+    rules.shuffle(rng);
+
+    Puzzle::new(lines(rules), part1, inside[gold])
+}
+
+#[derive(Clone, Copy)]
+enum Op {
+    Acc,
+    Jmp,
+    Nop,
+}
+
+// Ok(acc) when the program runs off the end, Err(acc) once it loops
+fn boot(program: &[(Op, i64)]) -> Result<i64, i64> {
+    let mut seen = vec![false; program.len()];
+    let (mut acc, mut ip) = (0, 0);
+    while ip < program.len() {
+        if seen[ip] {
+            return Err(acc);
+        }
+        seen[ip] = true;
+        match program[ip] {
+            (Op::Acc, x) => acc += x,
+            (Op::Jmp, x) => {
+                ip = (ip as i64 + x) as usize;
+                continue;
+            }
+            (Op::Nop, _) => {}
+        }
+        ip += 1;
+    }
+    Ok(acc)
+}
+
+// a straight run of code with one jmp that sends it back into a loop. no
+// jump before that point can skip over it, so it's the only fix that works
+fn day8(rng: &mut StdRng, size: usize) -> Puzzle {
+    let size = size.max(2);
+    let corrupt = rng.gen_range(size / 2..size);
+
+    let mut program = vec![];
+    for idx in 0..size {
+        let ahead = if idx < corrupt {
+            corrupt - idx
+        } else {
+            size - idx
+        } as i64;
+        program.push(if idx == corrupt {
+            (Op::Jmp, -rng.gen_range(1..=idx.max(1) as i64))
+        } else {
+            match rng.gen_range(0..4) {
+                0 | 1 => (Op::Acc, rng.gen_range(-50..=50)),
+                2 => (Op::Jmp, rng.gen_range(1..=ahead.min(5))),
+                _ => (Op::Nop, rng.gen_range(-(idx as i64)..=ahead.min(5))),
+            }
+        });
+    }
+
+    let part1 = boot(&program).unwrap_err();
+    program[corrupt].0 = Op::Nop;
+    let part2 = boot(&program).unwrap();
+    program[corrupt].0 = Op::Jmp;
+
+    let input = lines(program.iter().map(|(op, x)| {
+        let op = match op {
+            Op::Acc => "acc",
+            Op::Jmp => "jmp",
+            Op::Nop => "nop",
+        };
+        format!("{} {:+}", op, x)
+    }));
+    Puzzle::new(input, part1, Some(part2))
+}
+
+const PREAMBLE: usize = 25;
+
+fn is_sum(window: &[usize], x: usize) -> bool {
+    window
+        .iter()
+        .any(|a| window.iter().any(|b| a != b && a + b == x))
+}
+
+// every number is a sum of two of the few smallest before it, which keeps the
+// growth slow enough for a thousand numbers to fit in a usize. one number is
+// instead planted as the sum of an earlier contiguous range
+fn day9(rng: &mut StdRng, size: usize) -> Puzzle {
+    let size = size.clamp(PREAMBLE + 2, 1000);
+
+    let mut numbers: Vec<usize> = (1..=4 * PREAMBLE).collect();
+    numbers.shuffle(rng);
+    numbers.truncate(PREAMBLE);
+
+    let planted = rng.gen_range(PREAMBLE + 1..size);
+    while numbers.len() < size {
+        let idx = numbers.len();
+        let mut window: Vec<usize> = numbers[idx - PREAMBLE..].to_vec();
+        window.sort_unstable();
+        window.dedup();
+
+        if idx == planted {
+            let len = rng.gen_range(2..=idx.min(17));
+            let start = rng.gen_range(0..=idx - len);
+            let sum = numbers[start..start + len].iter().sum();
+            if !is_sum(&window, sum) {
+                numbers.push(sum);
+            }
+        } else {
+            let smallest = &window[..window.len().min(6)];
+            let a = smallest.choose(rng).unwrap();
+            let b = smallest.iter().filter(|x| *x != a).collect::<Vec<_>>();
+            numbers.push(a + *b.choose(rng).unwrap());
+        }
+    }
+
+    let target = numbers[planted];
+    let part2 = (0..planted)
+        .find_map(|start| {
+            let mut sum = numbers[start];
+            (start + 1..planted).find_map(|end| {
+                sum += numbers[end];
+                if sum == target {
+                    let range = &numbers[start..=end];
+                    Some(range.iter().min().unwrap() + range.iter().max().unwrap())
+                } else {
+                    None
+                }
+            })
+        })
+        .unwrap();
+
+    Puzzle::new(lines(numbers), target, Some(part2))
+}
+
+fn day10(rng: &mut StdRng, size: usize) -> Puzzle {
+    let mut adapters = vec![];
+    let mut joltage = 0;
+    let (mut ones, mut threes) = (0, 1);
+    for _ in 0..size.max(1) {
+        let gap = *[1, 1, 1, 3].choose(rng).unwrap();
+        joltage += gap;
+        adapters.push(joltage);
+        if gap == 1 {
+            ones += 1;
+        } else {
+            threes += 1;
+        }
+    }
+
+    // ways[i] counts the arrangements from the wall up to adapters[i]
+    let mut ways: Vec<Option<u128>> = vec![];
+    for (idx, adapter) in adapters.iter().enumerate() {
+        let from_wall = if *adapter <= 3 { 1 } else { 0 };
+        ways.push(
+            (0..idx)
+                .filter(|x| adapter - adapters[*x] <= 3)
+                .try_fold(from_wall, |acc: u128, x| acc.checked_add(ways[x]?)),
+        );
+    }
+
+    adapters.shuffle(rng);
+    Puzzle::new(lines(adapters), ones * threes, *ways.last().unwrap())
+}
+
+// the neighbouring seats of every seat, either adjacent or in sight
+fn neighbours(seats: &[Vec<bool>], sight: bool) -> HashMap<(usize, usize), Vec<(usize, usize)>> {
+    let mut out = HashMap::new();
+    for (y, row) in seats.iter().enumerate() {
+        for (x, seat) in row.iter().enumerate() {
+            if !seat {
+                continue;
+            }
+            let mut seen = vec![];
+            for (dy, dx) in &[
+                (-1, -1),
+                (-1, 0),
+                (-1, 1),
+                (0, -1),
+                (0, 1),
+                (1, -1),
+                (1, 0),
+                (1, 1),
+            ] {
+                let (mut ny, mut nx) = (y as isize + dy, x as isize + dx);
+                while ny >= 0 && nx >= 0 && (ny as usize) < seats.len() && (nx as usize) < row.len()
+                {
+                    if seats[ny as usize][nx as usize] {
+                        seen.push((ny as usize, nx as usize));
+                        break;
+                    }
+                    if !sight {
+                        break;
+                    }
+                    ny += dy;
+                    nx += dx;
+                }
+            }
+            out.insert((y, x), seen);
+        }
+    }
+    out
+}
+
+fn settle(seats: &[Vec<bool>], sight: bool, tolerance: usize, steps: usize) -> Option<usize> {
+    let neighbours = neighbours(seats, sight);
+    let mut occupied: HashSet<(usize, usize)> = HashSet::new();
+    for _ in 0..steps {
+        let next: HashSet<_> = neighbours
+            .iter()
+            .filter(|(seat, near)| {
+                let count = near.iter().filter(|x| occupied.contains(x)).count();
+                if occupied.contains(seat) {
+                    count < tolerance
+                } else {
+                    count == 0
+                }
+            })
+            .map(|(seat, _)| *seat)
+            .collect();
+        if next == occupied {
+            return Some(occupied.len());
+        }
+        occupied = next;
+    }
+    None
+}
+
+// dense random layouts can take a very long time to settle, so layouts are
+// kept fairly sparse and redrawn until both rules settle
+fn day11(rng: &mut StdRng, size: usize) -> Puzzle {
+    let size = size.max(1);
+    loop {
+        let seats: Vec<Vec<bool>> = (0..size)
+            .map(|_| (0..size).map(|_| rng.gen_bool(0.6)).collect())
+            .collect();
+        let steps = 10 * size + 100;
+
+        if let (Some(part1), Some(part2)) = (
+            settle(&seats, false, 4, steps),
+            settle(&seats, true, 5, steps),
+        ) {
+            let input = lines(seats.iter().map(|row| {
+                row.iter()
+                    .map(|x| if *x { 'L' } else { '.' })
+                    .collect::<String>()
+            }));
+            return Puzzle::new(input, part1, Some(part2));
+        }
+    }
+}
+
+fn day12(rng: &mut StdRng, size: usize) -> Puzzle {
+    // (north, east), with turns done in quarter turns to the left
+    let turn = |(n, e): (i64, i64), quarters: i64| match quarters.rem_euclid(4) {
+        0 => (n, e),
+        1 => (e, -n),
+        2 => (-n, -e),
+        _ => (-e, n),
+    };
+
+    let mut input = String::new();
+    let (mut ship, mut heading) = ((0, 0), (0, 1));
+    let (mut other, mut waypoint) = ((0, 0), (1, 10));
+    for _ in 0..size {
+        let letter = *b"NSEWLRFFF".choose(rng).unwrap() as char;
+        let amount = match letter {
+            'L' | 'R' => *[90, 180, 270].choose(rng).unwrap(),
+            _ => rng.gen_range(1..=100),
+        };
+        writeln!(input, "{}{}", letter, amount).unwrap();
+
+        let step = match letter {
+            'N' => (amount, 0),
+            'S' => (-amount, 0),
+            'E' => (0, amount),
+            'W' => (0, -amount),
+            _ => (0, 0),
+        };
+        ship = (ship.0 + step.0, ship.1 + step.1);
+        waypoint = (waypoint.0 + step.0, waypoint.1 + step.1);
+        match letter {
+            'L' => {
+                heading = turn(heading, amount / 90);
+                waypoint = turn(waypoint, amount / 90);
+            }
+            'R' => {
+                heading = turn(heading, -amount / 90);
+                waypoint = turn(waypoint, -amount / 90);
+            }
+            'F' => {
+                ship = (ship.0 + heading.0 * amount, ship.1 + heading.1 * amount);
+                other = (other.0 + waypoint.0 * amount, other.1 + waypoint.1 * amount);
+            }
+            _ => {}
+        }
+    }
+
+    Puzzle::new(
+        input,
+        ship.0.abs() + ship.1.abs(),
+        Some(other.0.abs() + other.1.abs()),
+    )
+}
+
+fn is_prime(n: u64) -> bool {
+    n > 1
+        && (2..)
+            .take_while(|x| x * x <= n)
+            .all(|x| !n.is_multiple_of(x))
+}
+
+// pick the contest time first, then put each bus at any offset that agrees
+// with it, which can be past its own id
+fn day13(rng: &mut StdRng, size: usize) -> Puzzle {
+    let size = size.max(8);
+    let primes: Vec<u64> = (3..=size as u64).filter(|x| is_prime(*x)).collect();
+
+    let (busses, answer) = loop {
+        let time = rng.gen_range(1..1u64 << 60);
+        let mut busses = vec![None; size];
+        let mut period = 1u64;
+        for bus in primes.choose_multiple(rng, size / 6 + 1) {
+            let first = ((bus - time % bus) % bus) as usize;
+            let offsets: Vec<_> = (first..size).step_by(*bus as usize).collect();
+            let offset = *offsets.choose(rng).unwrap();
+            match period.checked_mul(*bus) {
+                Some(next) if busses[offset].is_none() && next < 1 << 62 => {
+                    busses[offset] = Some(*bus);
+                    period = next;
+                }
+                _ => {}
+            }
+        }
+        if time % period != 0 {
+            break (busses, time % period);
+        }
+    };
+
+    let departure = rng.gen_range(1..1_000_000u64);
+    let part1 = busses
+        .iter()
+        .filter_map(|x| *x)
+        .map(|bus| (bus - departure % bus, bus))
+        .min()
+        .map(|(wait, bus)| wait * bus)
+        .unwrap();
+
+    let list: Vec<String> = busses
+        .iter()
+        .map(|x| x.map_or("x".to_owned(), |x| x.to_string()))
+        .collect();
+    Puzzle::new(
+        format!("{}\n{}\n", departure, list.join(",")),
+        part1,
+        Some(answer),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solver;
+
+    fn solve(day: u32, part: u32, input: &str) -> String {
+        let solver = solver::find(2020, day).unwrap();
+        let parsed = solver.parse_any(input).unwrap();
+        parsed.solve(part).unwrap().to_string()
+    }
+
+    #[test]
+    fn planted_answers() {
+        for day in 1..=13 {
+            for seed in 0..4 {
+                for size in &[1, 30] {
+                    let puzzle = generate(day, seed, *size).unwrap();
+                    let context = format!("day {} seed {} size {}", day, seed, size);
+                    if let Some(answer) = puzzle.part1 {
+                        assert_eq!(solve(day, 1, &puzzle.input), answer, "{}", context);
+                    }
+                    if let Some(answer) = puzzle.part2 {
+                        assert_eq!(solve(day, 2, &puzzle.input), answer, "{}", context);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn seeded() {
+        assert_eq!(generate(8, 7, 50), generate(8, 7, 50));
+        assert_ne!(generate(8, 7, 50), generate(8, 8, 50));
+        assert_eq!(generate(14, 0, 10), None);
+    }
+}
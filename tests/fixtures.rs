@@ -0,0 +1,105 @@
+// runs every example under tests/fixtures/<year>/day<n>/ through that day's
+// solver. each <name>.txt sits next to a <name>.answers with lines like
+// `part1: 514579`, and a part that isn't listed there isn't checked
+use aoc::solver;
+use std::fs;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+
+fn numbered(name: &str, prefix: &str) -> Option<u32> {
+    name.strip_prefix(prefix)?.parse().ok()
+}
+
+fn sorted_entries(dir: &Path) -> Vec<PathBuf> {
+    let mut entries: Vec<_> = fs::read_dir(dir)
+        .unwrap()
+        .map(|x| x.unwrap().path())
+        .collect();
+    entries.sort();
+    entries
+}
+
+fn answers(path: &Path) -> Result<Vec<(u32, String)>, String> {
+    let contents =
+        fs::read_to_string(path).map_err(|e| format!("couldn't read {}: {}", path.display(), e))?;
+    contents
+        .lines()
+        .filter(|x| !x.trim().is_empty())
+        .map(|line| {
+            let mut halves = line.splitn(2, ':');
+            let part = halves.next().and_then(|x| numbered(x.trim(), "part"));
+            match (part, halves.next()) {
+                (Some(part), Some(answer)) => Ok((part, answer.trim().to_owned())),
+                _ => Err(format!("{}: expected `partN: answer`", path.display())),
+            }
+        })
+        .collect()
+}
+
+fn check(year: u32, day: u32, example: &Path) -> Result<usize, String> {
+    let solver = solver::find(year, day).ok_or(format!("no solver for {} day {}", year, day))?;
+    let answers = answers(&example.with_extension("answers"))?;
+    let input = fs::read_to_string(example).unwrap();
+
+    for (part, expected) in &answers {
+        let actual = panic::catch_unwind(AssertUnwindSafe(|| {
+            let parsed = solver.parse_any(&input).map_err(|e| e.to_string())?;
+            parsed
+                .solve(*part)
+                .map(|x| x.to_string())
+                .map_err(|e| e.to_string())
+        }))
+        .unwrap_or_else(|_| Err("panicked".to_owned()));
+
+        match actual {
+            Ok(actual) if actual == *expected => {}
+            Ok(actual) => {
+                return Err(format!(
+                    "{} part {}: expected {} but got {}",
+                    example.display(),
+                    part,
+                    expected,
+                    actual
+                ))
+            }
+            Err(e) => return Err(format!("{} part {}: {}", example.display(), part, e)),
+        }
+    }
+    Ok(answers.len())
+}
+
+#[test]
+fn examples() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures");
+    let mut checked = 0;
+    let mut failures = vec![];
+
+    for year_dir in sorted_entries(&root) {
+        let year = year_dir.file_name().and_then(|x| x.to_str()?.parse().ok());
+        for day_dir in sorted_entries(&year_dir) {
+            let day = day_dir
+                .file_name()
+                .and_then(|x| numbered(x.to_str()?, "day"));
+            let (year, day) = match (year, day) {
+                (Some(year), Some(day)) => (year, day),
+                _ => {
+                    failures.push(format!("{} isn't <year>/day<n>", day_dir.display()));
+                    continue;
+                }
+            };
+
+            for example in sorted_entries(&day_dir) {
+                if example.extension().and_then(|x| x.to_str()) != Some("txt") {
+                    continue;
+                }
+                match check(year, day, &example) {
+                    Ok(parts) => checked += parts,
+                    Err(e) => failures.push(e),
+                }
+            }
+        }
+    }
+
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+    assert!(checked > 0, "no examples under {}", root.display());
+}
@@ -0,0 +1,34 @@
+// runs under node with `wasm-pack test --node -- --test wasm`
+#![cfg(target_arch = "wasm32")]
+
+use aoc::wasm::{days, solve};
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[wasm_bindgen_test]
+fn every_day() {
+    // new-day only adds to the list, so the days solved so far come first
+    let days = days();
+    let solved: Vec<u32> = (1..=13).collect();
+    assert_eq!(days[..solved.len()], solved[..]);
+    assert!(days.windows(2).all(|x| x[0] < x[1]));
+}
+
+#[wasm_bindgen_test]
+fn answers() {
+    let input = include_str!("fixtures/2020/day1/example.txt");
+    assert_eq!(solve(1, 1, input), Ok("514579".to_owned()));
+    assert_eq!(solve(1, 2, input), Ok("241861950".to_owned()));
+
+    let input = include_str!("fixtures/2020/day7/example.txt");
+    assert_eq!(solve(7, 2, input), Ok("32".to_owned()));
+}
+
+#[wasm_bindgen_test]
+fn errors() {
+    let next = days().last().unwrap() + 1;
+    assert_eq!(
+        solve(next, 1, ""),
+        Err(format!("no solution for day {}", next))
+    );
+    assert_eq!(solve(1, 3, "1721"), Err("there is no part 3".to_owned()));
+}